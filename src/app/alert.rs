@@ -0,0 +1,8 @@
+/// Price change alert for a favourite house.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Alert {
+    pub(crate) message: std::string::String,
+    /// Sent immediately even during quiet hours.
+    pub(crate) urgent: std::primitive::bool,
+}
@@ -0,0 +1,22 @@
+/// Straight line distances in kilometers to nearest amenities.
+pub(crate) type AmenityKm = std::collections::HashMap<crate::app::Amenity, std::primitive::f64>;
+
+/// Trait to define source of OpenStreetMap amenities.
+pub(crate) trait Amenities: Send + Sync {
+    /// Straight line distances in kilometers to nearest amenities.
+    /// Amenities not found within the radius are missing from the map.
+    ///
+    /// # Arguments
+    /// * `location` - Location to search around.
+    fn nearest_km<'a>(
+        &'a self,
+        location: longitude::Location,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<
+                    Output = std::result::Result<crate::app::AmenityKm, crate::client::JSONError>,
+                > + Send
+                + 'a,
+        >,
+    >;
+}
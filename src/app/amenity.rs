@@ -0,0 +1,83 @@
+/// Amenity type to search from OpenStreetMap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Amenity {
+    Grocery,
+    School,
+    Daycare,
+    TrainStation,
+    HealthCentre,
+}
+
+impl Amenity {
+    /// All amenities in the order they are shown.
+    pub(crate) const ALL: [Amenity; 5] = [
+        Amenity::Grocery,
+        Amenity::School,
+        Amenity::Daycare,
+        Amenity::TrainStation,
+        Amenity::HealthCentre,
+    ];
+
+    /// Amenity from argument value.
+    ///
+    /// # Arguments
+    /// * `name` - One of: GROCERY, SCHOOL, DAYCARE, TRAIN_STATION or HEALTH_CENTRE
+    pub(crate) fn from_name(name: &std::primitive::str) -> std::option::Option<Self> {
+        match name {
+            "GROCERY" => Some(Amenity::Grocery),
+            "SCHOOL" => Some(Amenity::School),
+            "DAYCARE" => Some(Amenity::Daycare),
+            "TRAIN_STATION" => Some(Amenity::TrainStation),
+            "HEALTH_CENTRE" => Some(Amenity::HealthCentre),
+            _ => None,
+        }
+    }
+
+    /// Keys of the OpenStreetMap tags that amenities are matched by.
+    pub(crate) const TAG_KEYS: [&'static std::primitive::str; 4] =
+        ["shop", "amenity", "railway", "healthcare"];
+
+    /// Argument value for the amenity.
+    pub(crate) fn name(&self) -> &'static std::primitive::str {
+        match self {
+            Amenity::Grocery => "GROCERY",
+            Amenity::School => "SCHOOL",
+            Amenity::Daycare => "DAYCARE",
+            Amenity::TrainStation => "TRAIN_STATION",
+            Amenity::HealthCentre => "HEALTH_CENTRE",
+        }
+    }
+
+    /// Title for the amenity.
+    pub(crate) fn title(&self) -> &'static std::primitive::str {
+        match self {
+            Amenity::Grocery => "Grocery store",
+            Amenity::School => "School",
+            Amenity::Daycare => "Daycare",
+            Amenity::TrainStation => "Train station",
+            Amenity::HealthCentre => "Health centre",
+        }
+    }
+
+    /// Do the OpenStreetMap tags match the amenity?
+    ///
+    /// # Arguments
+    /// * `tags` - Tags of the element.
+    pub(crate) fn matches(
+        &self,
+        tags: &std::collections::HashMap<std::string::String, std::string::String>,
+    ) -> std::primitive::bool {
+        let tag = |key: &std::primitive::str| -> &std::primitive::str {
+            tags.get(key).map(|value| value.as_str()).unwrap_or("")
+        };
+        match self {
+            Amenity::Grocery => ["supermarket", "convenience"].contains(&tag("shop")),
+            Amenity::School => tag("amenity") == "school",
+            Amenity::Daycare => tag("amenity") == "kindergarten",
+            Amenity::TrainStation => {
+                ["station", "halt"].contains(&tag("railway")) && tag("station") != "subway"
+            }
+            Amenity::HealthCentre => tag("healthcare") == "centre" || tag("amenity") == "clinic",
+        }
+    }
+}
@@ -18,6 +18,9 @@ pub(crate) trait Announcement {
     /// Street address.
     fn street_address(&self) -> std::string::String;
 
+    /// Municipality.
+    fn municipality(&self) -> std::option::Option<std::string::String>;
+
     /// Construction year.
     fn year(&self) -> std::option::Option<std::primitive::u16>;
 
@@ -39,4 +42,19 @@ pub(crate) trait Announcement {
     /// * `cache` - Use cache for HTTP request?
     async fn text(&mut self)
         -> std::result::Result<std::string::String, crate::etuovi::RegexError>;
+
+    /// Heating system.
+    async fn heating(
+        &mut self,
+    ) -> std::result::Result<std::option::Option<std::string::String>, crate::etuovi::RegexError>;
+
+    /// Energy class.
+    async fn energy_class(
+        &mut self,
+    ) -> std::result::Result<std::option::Option<std::string::String>, crate::etuovi::RegexError>;
+
+    /// URL for the main photo.
+    async fn photo_url(
+        &mut self,
+    ) -> std::result::Result<std::option::Option<std::string::String>, crate::etuovi::RegexError>;
 }
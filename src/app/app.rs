@@ -1,128 +1,633 @@
+/// Run single search and send results to every notifier.
+/// Telegram chats with the same saved search share one run.
+/// Notifiers that are not tied to a Telegram chat get results of the search without saved search.
+/// During quiet hours notifications are queued, see `QuietHours`.
+///
+/// # Arguments
+/// * `args` - Application arguments.
+pub(crate) async fn search(args: super::Args) -> std::result::Result<(), super::Error> {
+    let saved_searches: std::collections::HashMap<std::primitive::i64, super::SavedSearch> =
+        crate::store::Store::new(super::SAVED_SEARCHES)?.load()?;
+    let mut groups: std::vec::Vec<(super::SavedSearch, std::vec::Vec<std::primitive::i64>)> =
+        std::vec::Vec::<(super::SavedSearch, std::vec::Vec<std::primitive::i64>)>::new();
+    if args.has_shared_notifiers() || args.telegram_chat_ids().is_empty() {
+        groups.push((
+            super::SavedSearch::default(),
+            std::vec::Vec::<std::primitive::i64>::new(),
+        ));
+    }
+    for chat_id in args.telegram_chat_ids() {
+        let saved_search: super::SavedSearch =
+            saved_searches.get(&chat_id).cloned().unwrap_or_default();
+        match groups
+            .iter_mut()
+            .find(|(group_saved_search, _)| *group_saved_search == saved_search)
+        {
+            Some((_, chat_ids)) => chat_ids.push(chat_id),
+            None => groups.push((saved_search, std::vec![chat_id])),
+        }
+    }
+    // Output is shared, so that results of every group end up in it.
+    let mut output: super::Output = super::Output::new(&args.output_format, args.output.as_deref());
+    for (saved_search, chat_ids) in groups {
+        let mut args: super::Args = args.clone();
+        if saved_search != super::SavedSearch::default() {
+            args.clear_shared_notifiers();
+        }
+        saved_search.apply(&mut args);
+        let key: std::string::String = chat_ids
+            .iter()
+            .map(|chat_id| chat_id.to_string())
+            .collect::<std::vec::Vec<std::string::String>>()
+            .join(",");
+        let mut notifiers: std::vec::Vec<std::sync::Arc<dyn super::Notifier>> =
+            notifiers(&args, chat_ids);
+        if let (Some(start), Some(end)) = (args.quiet_hours_start, args.quiet_hours_end) {
+            notifiers = std::vec![std::sync::Arc::new(super::QuietHours::new(
+                start, end, key, notifiers
+            ))];
+        }
+        let _: std::vec::Vec<super::Result> = run(args, notifiers, &mut output).await?;
+    }
+    if let Some(path) = output.finish()? {
+        print(args.output_to_stdout(), &format!("Wrote file: {}", path));
+    }
+    Ok(())
+}
+
+/// Notifiers to send results to.
+///
+/// # Arguments
+/// * `args` - Application arguments.
+/// * `chat_ids` - Telegram chat ids to send results to.
+pub(crate) fn notifiers(
+    args: &super::Args,
+    chat_ids: std::vec::Vec<std::primitive::i64>,
+) -> std::vec::Vec<std::sync::Arc<dyn super::Notifier>> {
+    let mut notifiers: std::vec::Vec<std::sync::Arc<dyn super::Notifier>> =
+        std::vec::Vec::<std::sync::Arc<dyn super::Notifier>>::new();
+    if let Some(telegram_bot_token) = &args.telegram_bot_token {
+        if !chat_ids.is_empty() {
+            notifiers.push(std::sync::Arc::new(crate::telegram::Telegram::new(
+                telegram_bot_token,
+                chat_ids,
+                args.telegram_digest,
+                args.telegram_digest_top,
+            )));
+        }
+    }
+    if let Some(email) = email(args) {
+        notifiers.push(std::sync::Arc::new(email));
+    }
+    for (urls, kind) in [
+        (&args.webhook_urls, crate::webhook::WebhookKind::Json),
+        (
+            &args.discord_webhook_urls,
+            crate::webhook::WebhookKind::Discord,
+        ),
+        (&args.slack_webhook_urls, crate::webhook::WebhookKind::Slack),
+    ] {
+        for url in urls {
+            notifiers.push(std::sync::Arc::new(crate::webhook::Webhook::new(url, kind)));
+        }
+    }
+    for ntfy_topic in &args.ntfy_topics {
+        notifiers.push(std::sync::Arc::new(crate::ntfy::Ntfy::new(
+            ntfy_topic,
+            args.ntfy_token.as_deref(),
+        )));
+    }
+    if let (Some(matrix_homeserver), Some(matrix_access_token)) =
+        (&args.matrix_homeserver, &args.matrix_access_token)
+    {
+        if !args.matrix_room_ids.is_empty() {
+            notifiers.push(std::sync::Arc::new(crate::matrix::Matrix::new(
+                matrix_homeserver,
+                matrix_access_token,
+                args.matrix_room_ids.clone(),
+            )));
+        }
+    }
+    notifiers
+}
+
 /// Run the application.
 ///
 /// # Arguments
 /// * `args` - Application arguments.
+/// * `notifiers` - Notifiers to send results to.
+/// * `output` - Output to write results to.
+///
+/// # Returns
+/// Sorted results.
 pub(crate) async fn run(
     args: super::Args,
-    telegram: std::option::Option<crate::telegram::Telegram>,
-) -> std::result::Result<(), super::Error> {
+    notifiers: std::vec::Vec<std::sync::Arc<dyn super::Notifier>>,
+    output: &mut super::Output,
+) -> std::result::Result<std::vec::Vec<super::Result>, super::Error> {
     let mut handles: std::vec::Vec<
-        tokio::task::JoinHandle<std::result::Result<std::vec::Vec<super::Result>, super::Error>>,
+        tokio::task::JoinHandle<std::result::Result<std::vec::Vec<super::Outcome>, super::Error>>,
     > = std::vec::Vec::<
-        tokio::task::JoinHandle<std::result::Result<std::vec::Vec<super::Result>, super::Error>>,
+        tokio::task::JoinHandle<std::result::Result<std::vec::Vec<super::Outcome>, super::Error>>,
     >::new();
-    let location_comparison: std::option::Option<longitude::Location> =
-        match args.location_latitude.clone() {
-            Some(location_latitude) => match args.location_longitude.clone() {
-                Some(location_longitude) => Some(longitude::Location::from(
-                    location_latitude,
-                    location_longitude,
-                )),
-                // Should not happen if clap is configured and working correctly.
-                None => std::panic!("--location-latitude was given but not --location-longitude!"),
-            },
-            None => {
-                if args.telegram_user_id.is_some() {
-                    // Should not happen if clap is configured and working correctly.
-                    std::panic!("--location-longitude was given but not --location-latitude!");
-                }
-                None
-            }
-        };
+    let house_settings: std::sync::Arc<super::HouseSettings> =
+        std::sync::Arc::new(house_settings(&args).await?);
     let cache_etuovi_announcements: std::primitive::bool = args.cache_etuovi_announcements;
     let cache_etuovi_html: std::primitive::bool = args.cache_etuovi_html;
-    let cache_elisa_fixed_broadband_products: std::primitive::bool =
-        args.cache_elisa_fixed_broadband_products;
-    let open_route_service_token: std::option::Option<std::string::String> =
-        args.open_route_service_token.clone();
     let cities: std::vec::Vec<std::string::String> = args.cities.clone();
-    let house_min_square_meters: std::option::Option<std::primitive::u16> =
-        args.house_min_square_meters;
-    let max_distance_km: std::option::Option<std::primitive::u16> = args.max_distance_km;
-    let min_mbps: std::option::Option<std::primitive::u32> = args.min_mbps;
-    let exclude_texts: std::vec::Vec<std::string::String> = args
-        .exclude_texts
-        .iter()
-        .map(|text| text.to_lowercase())
-        .collect();
+    let export_internet_offers: std::primitive::bool = args.export_internet_offers;
+    let explain: std::primitive::bool = args.explain;
+    let sort_by: std::string::String = args.sort_by.clone();
+    let urgent_min_score: std::option::Option<std::primitive::f64> = args.urgent_min_score;
+    let output_to_stdout: std::primitive::bool = args.output_to_stdout();
+    let urgent_min_price_drop: std::option::Option<std::primitive::u32> =
+        args.urgent_min_price_drop;
     handles.push(tokio::task::spawn(async move {
         etuovi(
             &args.publishing_time_search_criteria,
-            location_comparison,
             cache_etuovi_announcements,
             cache_etuovi_html,
-            cache_elisa_fixed_broadband_products,
-            open_route_service_token,
             args.price_max,
             cities,
-            house_min_square_meters,
-            max_distance_km,
-            min_mbps,
-            exclude_texts,
+            house_settings,
         )
         .await
     }));
 
-    let mut results: std::vec::Vec<super::Result> = std::vec::Vec::<super::Result>::new();
+    let mut outcomes: std::vec::Vec<super::Outcome> = std::vec::Vec::<super::Outcome>::new();
     for handle in handles {
-        for result in handle.await?? {
-            results.push(result);
+        outcomes.extend(handle.await??);
+    }
+    let store: crate::store::Store = crate::store::Store::new(super::LISTINGS)?;
+    let listings: std::collections::HashMap<std::string::String, super::Listing> = store.load()?;
+    let mut alerts: std::vec::Vec<super::Alert> = std::vec::Vec::<super::Alert>::new();
+    for outcome in &mut outcomes {
+        if let Some(listing) = listings.get(&outcome.url) {
+            if listing.hidden {
+                // Hidden houses are never notified again.
+                outcome.decision = super::Decision::reject("hidden", "hidden", "not hidden");
+                outcome.result = None;
+            } else if listing.favourite {
+                if let (Some(euros_before), Some(euros)) = (listing.euros, outcome.euros) {
+                    if euros_before != euros {
+                        alerts.push(super::Alert {
+                            message: format!(
+                                "Price changed for favourite {}: {} € -> {} €",
+                                outcome.url, euros_before, euros
+                            ),
+                            urgent: match urgent_min_price_drop {
+                                Some(urgent_min_price_drop) => {
+                                    euros < euros_before
+                                        && urgent_min_price_drop <= euros_before - euros
+                                }
+                                None => false,
+                            },
+                        });
+                    }
+                }
+            }
+        }
+    }
+    let mut rejections: std::collections::BTreeMap<
+        &'static std::primitive::str,
+        std::primitive::usize,
+    > = std::collections::BTreeMap::<&'static std::primitive::str, std::primitive::usize>::new();
+    for outcome in &outcomes {
+        if let Some(filter) = outcome.decision.filter() {
+            *rejections.entry(filter).or_insert(0) += 1;
         }
     }
+    if explain {
+        for outcome in &outcomes {
+            print(
+                output_to_stdout,
+                &format!("{}: {}", outcome.url, outcome.decision),
+            );
+        }
+        print(
+            output_to_stdout,
+            &format!("Wrote file: {}", super::Outcome::write_report(&outcomes)?),
+        );
+    }
+    let prices: std::vec::Vec<(
+        std::string::String,
+        std::option::Option<std::primitive::u32>,
+    )> = outcomes
+        .iter()
+        .filter(|outcome| outcome.result.is_some() || listings.contains_key(&outcome.url))
+        .map(|outcome| (outcome.url.clone(), outcome.euros))
+        .collect();
+    let mut results: std::vec::Vec<super::Result> = outcomes
+        .into_iter()
+        .filter_map(|outcome| outcome.result)
+        .collect();
 
-    let telegram: std::option::Option<crate::telegram::Telegram> = telegram.clone();
+    for alert in &alerts {
+        print(output_to_stdout, &alert.message);
+    }
+    let mut houses: std::vec::Vec<super::HouseNotification> =
+        std::vec::Vec::<super::HouseNotification>::new();
+    results.sort_by(|a, b| super::Result::compare(a, b, &sort_by));
     if !results.is_empty() {
-        results.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
         for result in &results {
-            let message: std::string::String = result.message();
-            println!("{}", &message);
-            if let Some(telegram) = &telegram {
-                let _: teloxide::prelude::Message = telegram.send_message(&message).await?;
+            let mut extra: std::string::String = std::string::String::new();
+            if let Some(listing) = listings.get(result.url()) {
+                if listing.favourite {
+                    extra.push_str("\n\t⭐ Favourite");
+                }
+                if let Some(note) = &listing.note {
+                    extra.push_str(&format!("\n\t📝 {}", note));
+                }
             }
+            let house: super::HouseNotification = result.notification(&extra, urgent_min_score);
+            print(output_to_stdout, &house.message);
+            houses.push(house);
+        }
+        if export_internet_offers {
+            print(
+                output_to_stdout,
+                &format!(
+                    "Wrote file: {}",
+                    super::Result::write_internet_offers_csv(&results)?
+                ),
+            );
+            print(
+                output_to_stdout,
+                &format!(
+                    "Wrote file: {}",
+                    super::Result::write_internet_offers_json(&results)?
+                ),
+            );
         }
-        println!("Wrote file: {}", super::Result::write_csv(&results)?);
     }
+    output.write(&results)?;
+    // Notifiers send results as CSV file.
+    let csv_path: std::option::Option<std::string::String> =
+        if results.is_empty() || notifiers.is_empty() {
+            None
+        } else {
+            Some(super::Result::write_csv(&results)?)
+        };
+
+    let mut summary: std::string::String = format!("Found {}!", results.len());
+    for (filter, count) in &rejections {
+        summary.push_str(&format!("\nRejected by {}: {}", filter, count));
+    }
+    print(output_to_stdout, &summary);
+    let notification: super::Notification = super::Notification {
+        table_html: super::Result::table_html(&houses),
+        houses,
+        alerts,
+        summary,
+        csv_path,
+        digest: false,
+    };
+    // Every notifier is tried and listings are saved before reporting failed notifiers.
+    let notified: std::result::Result<(), super::Error> =
+        super::notify_all(&notifiers, &notification).await;
 
-    let message: std::string::String = format!("Found {}!", results.len());
-    println!("{}", message);
-    if let Some(telegram) = &telegram {
-        let _: teloxide::prelude::Message = telegram.send_message(&message).await?;
+    // Reload listings, because bot may have changed them during the search.
+    let mut listings: std::collections::HashMap<std::string::String, super::Listing> =
+        store.load()?;
+    for (url, euros) in prices {
+        listings.entry(url).or_default().euros = euros;
     }
+    store.save(&listings)?;
+    notified?;
+
+    Ok(results)
+}
 
-    return Ok(());
+/// Email sender for results.
+/// Only if SMTP server and recipients are given.
+///
+/// # Arguments
+/// * `args` - Application arguments.
+fn email(args: &super::Args) -> std::option::Option<crate::email::Email> {
+    if args.email_to.is_empty() {
+        return None;
+    }
+    match (&args.smtp_host, &args.email_from) {
+        (Some(smtp_host), Some(email_from)) => Some(crate::email::Email::new(
+            smtp_host,
+            args.smtp_port,
+            &args.smtp_tls,
+            match (&args.smtp_username, &args.smtp_password) {
+                (Some(smtp_username), Some(smtp_password)) => {
+                    Some((smtp_username.clone(), smtp_password.clone()))
+                }
+                _ => None,
+            },
+            args.smtp_insecure_auth,
+            email_from,
+            args.email_to.clone(),
+        )),
+        _ => None,
+    }
 }
 
+/// Location to compare houses against.
+/// Geocodes location address if given, else uses given latitude and longitude.
+///
+/// # Arguments
+/// * `args` - Application arguments.
+async fn location_comparison(
+    args: &super::Args,
+) -> std::result::Result<std::option::Option<longitude::Location>, super::Error> {
+    if let Some(location_address) = &args.location_address {
+        let open_route_service_token: &std::primitive::str = match &args.open_route_service_token {
+            Some(open_route_service_token) => open_route_service_token,
+            // Should not happen if clap is configured and working correctly.
+            None => std::panic!("--location-address was given but not --open-route-service-token!"),
+        };
+        let location: longitude::Location =
+            crate::open_route_service::OpenRouteService::new(open_route_service_token)
+                .map_err(crate::open_route_service::Error::from)?
+                .geocode(location_address)
+                .await?
+                .ok_or(super::Error::LocationNotFound(location_address.clone()))?;
+        print(
+            args.output_to_stdout(),
+            &format!(
+                "Geocoded location {} to latitude {} and longitude {}.",
+                location_address, location.latitude, location.longitude
+            ),
+        );
+        return Ok(Some(location));
+    }
+    Ok(match args.location_latitude {
+        Some(location_latitude) => match args.location_longitude {
+            Some(location_longitude) => Some(longitude::Location::from(
+                location_latitude,
+                location_longitude,
+            )),
+            // Should not happen if clap is configured and working correctly.
+            None => std::panic!("--location-latitude was given but not --location-longitude!"),
+        },
+        None => {
+            if args.location_longitude.is_some() {
+                // Should not happen if clap is configured and working correctly.
+                std::panic!("--location-longitude was given but not --location-latitude!");
+            }
+            None
+        }
+    })
+}
+
+/// Routing provider for biking distance.
+///
+/// # Arguments
+/// * `args` - Application arguments.
+fn routing(
+    args: &super::Args,
+) -> std::result::Result<std::option::Option<std::sync::Arc<dyn super::Routing>>, super::Error> {
+    Ok(match args.routing_provider.as_str() {
+        "OSRM" => match &args.routing_base_url {
+            Some(routing_base_url) => Some(std::sync::Arc::new(crate::osrm::Osrm::new(
+                routing_base_url,
+            ))),
+            // Should not happen if clap is configured and working correctly.
+            None => std::panic!("--routing-provider=OSRM was given but not --routing-base-url!"),
+        },
+        "GRAPHHOPPER" => match &args.routing_base_url {
+            Some(routing_base_url) => Some(std::sync::Arc::new(
+                crate::graphhopper::GraphHopper::new(routing_base_url),
+            )),
+            // Should not happen if clap is configured and working correctly.
+            None => {
+                std::panic!("--routing-provider=GRAPHHOPPER was given but not --routing-base-url!")
+            }
+        },
+        _ => match &args.routing_base_url {
+            Some(routing_base_url) => Some(std::sync::Arc::new(
+                crate::open_route_service::OpenRouteService::self_hosted(routing_base_url),
+            )),
+            None => match &args.open_route_service_token {
+                Some(open_route_service_token) => Some(std::sync::Arc::new(
+                    crate::open_route_service::OpenRouteService::new(open_route_service_token)
+                        .map_err(crate::open_route_service::Error::from)?,
+                )),
+                None => None,
+            },
+        },
+    })
+}
+
+/// Digitransit client for public transport journey time.
+/// Only if subscription key or self-hosted base URL is given.
+/// Journeys depart on the next weekday at the given departure time.
+///
+/// # Arguments
+/// * `args` - Application arguments.
+fn transit(
+    args: &super::Args,
+) -> std::result::Result<
+    std::option::Option<std::sync::Arc<crate::digitransit::Digitransit>>,
+    super::Error,
+> {
+    if args.digitransit_subscription_key.is_none() && args.digitransit_base_url.is_none() {
+        return Ok(None);
+    }
+    let mut date: chrono::NaiveDate = chrono::Local::now().date_naive();
+    loop {
+        date = date + chrono::Days::new(1);
+        match chrono::Datelike::weekday(&date) {
+            chrono::Weekday::Sat | chrono::Weekday::Sun => {}
+            _ => break,
+        }
+    }
+    Ok(Some(std::sync::Arc::new(
+        crate::digitransit::Digitransit::new(
+            args.digitransit_base_url.as_deref(),
+            args.digitransit_subscription_key.as_deref(),
+            date.and_time(args.transit_departure_time),
+        )?,
+    )))
+}
+
+/// Mobile network coverage from API or local GeoJSON file.
+///
+/// # Arguments
+/// * `args` - Application arguments.
+fn coverage(
+    args: &super::Args,
+) -> std::result::Result<std::option::Option<std::sync::Arc<crate::coverage::Coverage>>, super::Error>
+{
+    let mut properties: crate::coverage::Properties = crate::coverage::Properties::default();
+    for (field, property) in &args.coverage_properties {
+        // Fields are already validated when parsing arguments.
+        let _: std::result::Result<(), std::string::String> = properties.set(field, property);
+    }
+    if let Some(coverage_url) = &args.coverage_url {
+        return Ok(Some(std::sync::Arc::new(
+            crate::coverage::Coverage::from_url(coverage_url, properties, args.cache_coverage),
+        )));
+    }
+    if let Some(coverage_geojson) = &args.coverage_geojson {
+        return Ok(Some(std::sync::Arc::new(
+            crate::coverage::Coverage::from_geojson(coverage_geojson, properties)?,
+        )));
+    }
+    Ok(None)
+}
+
+/// Settings for checking houses.
+///
+/// # Arguments
+/// * `args` - Application arguments.
+async fn house_settings(
+    args: &super::Args,
+) -> std::result::Result<super::HouseSettings, super::Error> {
+    return Ok(super::HouseSettings {
+        location_comparison: location_comparison(args).await?,
+        open_route_service_token: args.open_route_service_token.clone(),
+        routing: routing(args)?,
+        transit: transit(args)?,
+        cache_elisa_fixed_broadband_products: args.cache_elisa_fixed_broadband_products,
+        house_min_square_meters: args.house_min_square_meters,
+        max_distance_km: args.max_distance_km,
+        min_mbps: args.min_mbps,
+        exclude_texts: args
+            .exclude_texts
+            .iter()
+            .map(|text| text.to_lowercase())
+            .collect(),
+        exclude_unknown_location: args.exclude_unknown_location,
+        exclude_patterns: args.exclude_patterns.clone(),
+        require_texts: args
+            .require_texts
+            .iter()
+            .chain(args.require_patterns.iter())
+            .cloned()
+            .collect(),
+        require_all_texts: args.require_texts_mode == "ALL",
+        amenities: amenities(args),
+        amenity_max_km: args.amenity_max_km.clone(),
+        broadband_providers: args
+            .broadband_providers
+            .iter()
+            .map(|(name, url_template)| {
+                std::sync::Arc::new(crate::broadband::Broadband::new(
+                    name,
+                    url_template,
+                    args.cache_broadband_products,
+                ))
+            })
+            .collect(),
+        cheapest_internet_min_mbps: args.cheapest_internet_min_mbps,
+        require_internet: args.require_internet,
+        require_internet_technologies: args
+            .require_internet_technologies
+            .iter()
+            .map(|technology| technology.to_lowercase())
+            .collect(),
+        coverage: coverage(args)?,
+        monthly_cost_settings: monthly_cost_settings(args),
+        max_euros_per_month: args.max_euros_per_month,
+        purchase_cost_settings: super::PurchaseCostSettings {
+            moving_renovation_euros: args.moving_renovation_euros,
+        },
+        price_max_total: if args.price_max_total {
+            args.price_max
+        } else {
+            None
+        },
+        score_weights: score_weights(args),
+        filters: args.filters.clone(),
+    });
+}
+
+/// Settings for estimating monthly cost of ownership.
+///
+/// # Arguments
+/// * `args` - Application arguments.
+fn monthly_cost_settings(args: &super::Args) -> super::MonthlyCostSettings {
+    super::MonthlyCostSettings {
+        loan_to_value_percent: args.loan_to_value_percent,
+        interest_rate_percent: args.interest_rate_percent,
+        loan_term_years: args.loan_term_years,
+        property_tax_percents: args.property_tax_rates.iter().cloned().collect(),
+        property_tax_default_percent: args.property_tax_percent,
+        commute_euros_per_route_km: args.commute_euros_per_route_km,
+    }
+}
+
+/// Weights for scoring houses.
+///
+/// # Arguments
+/// * `args` - Application arguments.
+fn score_weights(args: &super::Args) -> super::ScoreWeights {
+    super::ScoreWeights {
+        price_per_square_meter: args.weight_price_per_square_meter,
+        commute: args.weight_commute,
+        year: args.weight_year,
+        total_area: args.weight_total_area,
+        internet: args.weight_internet,
+        energy_class: args.weight_energy_class,
+        keywords: args.weight_keywords,
+        keyword_texts: args
+            .score_keywords
+            .iter()
+            .map(|keyword| keyword.to_lowercase())
+            .collect(),
+    }
+}
+
+/// Source for distances to nearest amenities, local extract if given, else Overpass.
+/// Only if amenities are asked for, amenity filter, self-hosted Overpass or local extract is given.
+/// Search radius covers the largest amenity filter.
+///
+/// # Arguments
+/// * `args` - Application arguments.
+fn amenities(args: &super::Args) -> std::option::Option<std::sync::Arc<dyn super::Amenities>> {
+    if !args.amenities
+        && args.amenity_max_km.is_empty()
+        && args.overpass_base_url.is_none()
+        && args.osm_pbf_path.is_none()
+    {
+        return None;
+    }
+    let radius_km: std::primitive::f64 = args
+        .amenity_max_km
+        .iter()
+        .fold(AMENITIES_RADIUS_KM, |radius_km, (_, max_km)| {
+            radius_km.max(*max_km)
+        });
+    Some(match &args.osm_pbf_path {
+        Some(osm_pbf_path) => {
+            std::sync::Arc::new(crate::osm_pbf::OsmPbf::new(osm_pbf_path, radius_km))
+        }
+        None => std::sync::Arc::new(crate::overpass::Overpass::new(
+            args.overpass_base_url.as_deref(),
+            radius_km,
+        )),
+    })
+}
+
+/// Default radius in kilometers to search amenities from.
+const AMENITIES_RADIUS_KM: std::primitive::f64 = 10.0;
+
 /// Handle Etuovi announcements.
 ///
 /// # Arguments
 /// * `publishing_time_search_criteria` - Search criteria for publishing time.
-/// * `location_comparison` - Optional location to compare against.
-/// * `cache` - Cache data that can be changed?
-/// * `open_route_service_token` - Optional OpenRouteService authorization token: https://openrouteservice.org/sign-up/
+/// * `cache_etuovi_announcements` - Cache Etuovi announcements search result?
+/// * `cache_etuovi_html` - Cache Etuovi HTML?
 /// * `price_max` - Optional maximum price.
 /// * `cities` - Cities.
-/// * `house_min_square_meters` - Optional minimum square meters for the house.
-/// * `max_distance_km` - Optional maximum distance in kilometers.
-/// * `min_mbps` - Optional minimum megabits per second.
-/// * `exclude_texts` - Exclude house if it's text data has one of these texts.
+/// * `house_settings` - Settings for checking houses.
 pub(self) async fn etuovi(
     publishing_time_search_criteria: &std::primitive::str,
-    location_comparison: std::option::Option<longitude::Location>,
     cache_etuovi_announcements: std::primitive::bool,
     cache_etuovi_html: std::primitive::bool,
-    cache_elisa_fixed_broadband_products: std::primitive::bool,
-    open_route_service_token: std::option::Option<std::string::String>,
     price_max: std::option::Option<std::primitive::u32>,
     cities: std::vec::Vec<std::string::String>,
-    house_min_square_meters: std::option::Option<std::primitive::u16>,
-    max_distance_km: std::option::Option<std::primitive::u16>,
-    min_mbps: std::option::Option<std::primitive::u32>,
-    exclude_texts: std::vec::Vec<std::string::String>,
-) -> std::result::Result<std::vec::Vec<super::Result>, super::Error> {
+    house_settings: std::sync::Arc<super::HouseSettings>,
+) -> std::result::Result<std::vec::Vec<super::Outcome>, super::Error> {
     let mut handles: std::vec::Vec<
-        tokio::task::JoinHandle<std::result::Result<Option<super::Result>, super::Error>>,
+        tokio::task::JoinHandle<std::result::Result<super::Outcome, super::Error>>,
     > = std::vec::Vec::<
-        tokio::task::JoinHandle<std::result::Result<Option<super::Result>, super::Error>>,
+        tokio::task::JoinHandle<std::result::Result<super::Outcome, super::Error>>,
     >::new();
     for announcement in crate::etuovi::Etuovi::new(
         cache_etuovi_announcements,
@@ -136,66 +641,43 @@ pub(self) async fn etuovi(
     .iter()
     {
         let announcement: crate::etuovi::Announcement = announcement.clone();
-        let location_comparison: std::option::Option<longitude::Location> =
-            location_comparison.clone();
-        let open_route_service_token: std::option::Option<std::string::String> =
-            open_route_service_token.clone();
-        let exclude_texts: std::vec::Vec<std::string::String> = exclude_texts.clone();
+        let house_settings: std::sync::Arc<super::HouseSettings> =
+            std::sync::Arc::clone(&house_settings);
         handles.push(tokio::task::spawn(async move {
-            etuovi_announcement(
-                announcement,
-                location_comparison,
-                cache_elisa_fixed_broadband_products,
-                open_route_service_token,
-                house_min_square_meters,
-                max_distance_km,
-                min_mbps,
-                exclude_texts,
-            )
-            .await
+            etuovi_announcement(announcement, house_settings).await
         }));
     }
 
-    let mut results: std::vec::Vec<super::Result> = std::vec::Vec::<super::Result>::new();
+    let mut outcomes: std::vec::Vec<super::Outcome> = std::vec::Vec::<super::Outcome>::new();
     for handle in handles {
-        if let Some(result) = handle.await?? {
-            results.push(result);
-        }
+        outcomes.push(handle.await??);
     }
-    return Ok(results);
+    Ok(outcomes)
 }
 
 /// Handle Etuovi announcement.
 ///
 /// # Arguments
 /// * `announcement` - Etuovi announcement.
-/// * `location_comparison` - Optional location_comparison to compare against.
-/// * `cache_elisa_fixed_broadband_products` - Cache Elisa fixed broadband products?
-/// * `open_route_service_token` - OpenRouteService authorization token: https://openrouteservice.org/sign-up/
-/// * `house_min_square_meters` - Optional minimum square meters for the house.
-/// * `max_distance_km` - Optional maximum distance in kilometers.
-/// * `min_mbps` - Optional minimum megabits per second.
-/// * `exclude_texts` - Exclude house if it's text data has one of these texts.
+/// * `house_settings` - Settings for checking houses.
 pub(self) async fn etuovi_announcement(
     announcement: crate::etuovi::Announcement,
-    location_comparison: std::option::Option<longitude::Location>,
-    cache_elisa_fixed_broadband_products: std::primitive::bool,
-    open_route_service_token: std::option::Option<std::string::String>,
-    house_min_square_meters: std::option::Option<std::primitive::u16>,
-    max_distance_km: std::option::Option<std::primitive::u16>,
-    min_mbps: std::option::Option<std::primitive::u32>,
-    exclude_texts: std::vec::Vec<std::string::String>,
-) -> std::result::Result<Option<super::Result>, super::Error> {
-    return Ok(crate::app::House::<crate::etuovi::Announcement>::new(
-        announcement,
-        location_comparison.clone(),
-        open_route_service_token,
-        cache_elisa_fixed_broadband_products,
-        house_min_square_meters,
-        max_distance_km,
-        min_mbps,
-        exclude_texts,
-    )
-    .result()
-    .await?);
+    house_settings: std::sync::Arc<super::HouseSettings>,
+) -> std::result::Result<super::Outcome, super::Error> {
+    crate::app::House::<crate::etuovi::Announcement>::new(announcement, house_settings)
+        .outcome()
+        .await
+}
+
+/// Print message to stdout, or to stderr if results are written to stdout.
+///
+/// # Arguments
+/// * `output_to_stdout` - Are results written to stdout?
+/// * `message` - Message to print.
+fn print(output_to_stdout: std::primitive::bool, message: &std::primitive::str) {
+    if output_to_stdout {
+        eprintln!("{}", message);
+    } else {
+        println!("{}", message);
+    }
 }
@@ -1,6 +1,14 @@
 // Arguments:
-#[derive(clap::Parser, Debug)]
+#[derive(clap::Parser, Debug, Clone)]
 #[command(version, about, long_about = None)]
+#[command(group(
+    clap::ArgGroup::new("location").args(["location_latitude", "location_address"])
+))]
+#[command(group(
+    clap::ArgGroup::new("telegram_recipients")
+        .args(["telegram_user_id", "telegram_chat_ids"])
+        .multiple(true)
+))]
 pub(crate) struct Args {
     // Search criteria for publishing time.
     #[arg(
@@ -17,6 +25,15 @@ pub(crate) struct Args {
     #[arg(long, help = "Max price in euros.")]
     pub(super) price_max: Option<std::primitive::u32>,
 
+    // Interpret maximum price as total cash outlay including one-off purchase costs?
+    #[arg(
+        long,
+        action,
+        help = "Interpret max price as total cash outlay including one-off purchase costs?",
+        requires = "price_max"
+    )]
+    pub(super) price_max_total: bool,
+
     // Cities.
     #[arg(long, help = "Cities.")]
     pub(super) cities: Vec<std::string::String>,
@@ -29,12 +46,59 @@ pub(crate) struct Args {
     #[arg(long, help = "Location longitude.", requires = "location_latitude")]
     pub(super) location_longitude: Option<std::primitive::f64>,
 
+    // Location address to geocode into latitude and longitude.
+    #[arg(
+        long,
+        help = "Location address to geocode into latitude and longitude.",
+        conflicts_with_all = ["location_latitude", "location_longitude"],
+        requires = "open_route_service_token"
+    )]
+    pub(super) location_address: Option<std::string::String>,
+
     // OpenRouteService authorization token: https://openrouteservice.org/sign-up/
     #[arg(long, help = "OpenRouteService authorization token.")]
     pub(super) open_route_service_token: Option<std::string::String>,
 
+    // Routing provider for biking distance.
+    #[arg(
+        long,
+        default_value="OPEN_ROUTE_SERVICE",
+        value_parser = clap::builder::PossibleValuesParser::new(
+            ["OPEN_ROUTE_SERVICE", "OSRM", "GRAPHHOPPER"]
+        ),
+        help = "Routing provider for biking distance. One of: OPEN_ROUTE_SERVICE, OSRM or GRAPHHOPPER"
+    )]
+    pub(super) routing_provider: std::string::String,
+
+    // Base URL for self-hosted routing provider.
+    #[arg(
+        long,
+        help = "Base URL for self-hosted routing provider.",
+        required_if_eq_any = [("routing_provider", "OSRM"), ("routing_provider", "GRAPHHOPPER")]
+    )]
+    pub(super) routing_base_url: Option<std::string::String>,
+
+    // Digitransit subscription key: https://portal-api.digitransit.fi/
+    #[arg(long, help = "Digitransit subscription key.")]
+    pub(super) digitransit_subscription_key: Option<std::string::String>,
+
+    // Base URL for self-hosted Digitransit compatible OpenTripPlanner GraphQL API.
+    #[arg(
+        long,
+        help = "Base URL for self-hosted Digitransit compatible OpenTripPlanner GraphQL API."
+    )]
+    pub(super) digitransit_base_url: Option<std::string::String>,
+
+    // Departure time for public transport journeys on the next weekday.
+    #[arg(
+        long,
+        default_value = "08:00",
+        help = "Departure time (HH:MM) for public transport journeys on the next weekday."
+    )]
+    pub(super) transit_departure_time: chrono::NaiveTime,
+
     // Telegram bot token: https://core.telegram.org/bots#botfather
-    #[arg(long, help = "Telegram bot token.", requires = "telegram_user_id")]
+    #[arg(long, help = "Telegram bot token.", requires = "telegram_recipients")]
     pub(crate) telegram_bot_token: Option<std::string::String>,
 
     // Send Telegram message to this user id: https://core.telegram.org/bots/api#user
@@ -45,10 +109,204 @@ pub(crate) struct Args {
     )]
     pub(crate) telegram_user_id: Option<std::primitive::u64>,
 
+    // Send Telegram messages also to these chats, group chat ids are negative.
+    #[arg(
+        long,
+        help = "Send Telegram messages also to these chat ids. Group chat ids are negative.",
+        requires = "telegram_bot_token",
+        allow_negative_numbers = true
+    )]
+    pub(crate) telegram_chat_ids: Vec<std::primitive::i64>,
+
+    // Send error messages to this chat instead of the recipients.
+    #[arg(
+        long,
+        help = "Send error messages to this Telegram chat id instead of the recipients.",
+        requires = "telegram_bot_token",
+        allow_negative_numbers = true
+    )]
+    pub(crate) telegram_admin_chat_id: Option<std::primitive::i64>,
+
+    // Run interactive Telegram bot instead of a single search.
+    #[arg(
+        long,
+        action,
+        help = "Run interactive Telegram bot that searches on demand instead of a single search.",
+        requires = "telegram_bot_token"
+    )]
+    pub(crate) telegram_bot: bool,
+
+    // Additional Telegram user ids authorized to use bot commands.
+    #[arg(
+        long,
+        help = "Additional Telegram user ids authorized to use bot commands.",
+        requires = "telegram_bot"
+    )]
+    pub(crate) telegram_authorized_user_ids: Vec<std::primitive::u64>,
+
+    // Send one digest with top houses and CSV file instead of a message per house.
+    #[arg(
+        long,
+        action,
+        help = "Send one Telegram digest with top houses and CSV file instead of a message per house.",
+        requires = "telegram_bot_token"
+    )]
+    pub(super) telegram_digest: bool,
+
+    // Number of top houses in the digest.
+    #[arg(
+        long,
+        default_value_t = 10,
+        help = "Number of top houses in the Telegram digest.",
+        requires = "telegram_digest"
+    )]
+    pub(crate) telegram_digest_top: std::primitive::usize,
+
+    // SMTP server host for sending emails.
+    #[arg(
+        long,
+        help = "SMTP server host for sending emails.",
+        requires = "email_from"
+    )]
+    pub(super) smtp_host: Option<std::string::String>,
+
+    // SMTP server port.
+    #[arg(long, default_value_t = 587, help = "SMTP server port.")]
+    pub(super) smtp_port: std::primitive::u16,
+
+    // TLS mode for SMTP server.
+    #[arg(
+        long,
+        default_value="STARTTLS",
+        value_parser = clap::builder::PossibleValuesParser::new(["NONE", "STARTTLS", "TLS"]),
+        help = "TLS mode for SMTP server. One of: NONE, STARTTLS or TLS"
+    )]
+    pub(super) smtp_tls: std::string::String,
+
+    // Username for SMTP server.
+    #[arg(
+        long,
+        help = "Username for SMTP server.",
+        requires = "smtp_password",
+        requires = "smtp_host"
+    )]
+    pub(super) smtp_username: Option<std::string::String>,
+
+    // Password for SMTP server.
+    #[arg(long, help = "Password for SMTP server.", requires = "smtp_username")]
+    pub(super) smtp_password: Option<std::string::String>,
+
+    // Allow sending SMTP credentials without TLS.
+    #[arg(
+        long,
+        help = "Allow sending SMTP credentials without TLS, when --smtp-tls is NONE.",
+        requires = "smtp_username"
+    )]
+    pub(super) smtp_insecure_auth: std::primitive::bool,
+
+    // Sender email address.
+    #[arg(long, help = "Sender email address.", requires = "smtp_host")]
+    pub(super) email_from: Option<std::string::String>,
+
+    // Send results by email to these addresses.
+    #[arg(
+        long,
+        help = "Send results by email to these addresses.",
+        requires = "smtp_host"
+    )]
+    pub(super) email_to: Vec<std::string::String>,
+
+    // Send results as JSON to these webhook URLs.
+    #[arg(long, help = "Send results as JSON to these webhook URLs.")]
+    pub(super) webhook_urls: Vec<std::string::String>,
+
+    // Send results to these Discord incoming webhook URLs.
+    #[arg(long, help = "Send results to these Discord incoming webhook URLs.")]
+    pub(super) discord_webhook_urls: Vec<std::string::String>,
+
+    // Send results to these Slack compatible incoming webhook URLs.
+    #[arg(
+        long,
+        help = "Send results to these Slack compatible incoming webhook URLs."
+    )]
+    pub(super) slack_webhook_urls: Vec<std::string::String>,
+
+    // Send results to these ntfy topic URLs: https://docs.ntfy.sh/publish/
+    #[arg(
+        long,
+        help = "Send results to these ntfy topic URLs, for example https://ntfy.sh/my-houses"
+    )]
+    pub(super) ntfy_topics: Vec<std::string::String>,
+
+    // Access token for ntfy server.
+    #[arg(long, help = "Access token for ntfy server.", requires = "ntfy_topics")]
+    pub(super) ntfy_token: Option<std::string::String>,
+
+    // Matrix homeserver URL.
+    #[arg(
+        long,
+        help = "Matrix homeserver URL, for example https://matrix.org",
+        requires = "matrix_access_token",
+        requires = "matrix_room_ids"
+    )]
+    pub(super) matrix_homeserver: Option<std::string::String>,
+
+    // Access token for Matrix user that sends results.
+    #[arg(
+        long,
+        help = "Access token for Matrix user that sends results.",
+        requires = "matrix_homeserver"
+    )]
+    pub(super) matrix_access_token: Option<std::string::String>,
+
+    // Send results to these Matrix rooms.
+    #[arg(
+        long,
+        help = "Send results to these Matrix room ids. User must have joined them.",
+        requires = "matrix_homeserver"
+    )]
+    pub(super) matrix_room_ids: Vec<std::string::String>,
+
+    // Start of quiet hours, during which notifications are queued.
+    #[arg(
+        long,
+        help = "Start time (HH:MM) of quiet hours, during which notifications of searches are queued and sent as digest after quiet hours.",
+        requires = "quiet_hours_end"
+    )]
+    pub(super) quiet_hours_start: Option<chrono::NaiveTime>,
+
+    // End of quiet hours.
+    #[arg(
+        long,
+        help = "End time (HH:MM) of quiet hours.",
+        requires = "quiet_hours_start"
+    )]
+    pub(super) quiet_hours_end: Option<chrono::NaiveTime>,
+
+    // Houses with at least this score are sent immediately even during quiet hours.
+    #[arg(
+        long,
+        help = "Houses with at least this score are sent immediately even during quiet hours.",
+        requires = "quiet_hours_start"
+    )]
+    pub(super) urgent_min_score: Option<std::primitive::f64>,
+
+    // Price drops of favourite houses of at least this many euros are sent immediately even during quiet hours.
+    #[arg(
+        long,
+        help = "Price drops of favourite houses of at least this many euros are sent immediately even during quiet hours.",
+        requires = "quiet_hours_start"
+    )]
+    pub(super) urgent_min_price_drop: Option<std::primitive::u32>,
+
     // Cache Elisa fixedBroadbandProducts result?
     #[arg(long, action, help = "Cache Elisa fixedBroadbandProducts result?")]
     pub(super) cache_elisa_fixed_broadband_products: bool,
 
+    // Cache broadband provider products result?
+    #[arg(long, action, help = "Cache broadband provider products result?")]
+    pub(super) cache_broadband_products: bool,
+
     // Cache Etuovi announcements search result?
     #[arg(long, action, help = "Cache Etuovi announcements search result?")]
     pub(super) cache_etuovi_announcements: bool,
@@ -66,8 +324,7 @@ pub(crate) struct Args {
         long,
         action,
         help = "Maximum distance to house from location in kilometers.",
-        requires = "location_longitude",
-        requires = "location_latitude"
+        requires = "location"
     )]
     pub(super) max_distance_km: Option<std::primitive::u16>,
 
@@ -75,7 +332,553 @@ pub(crate) struct Args {
     #[arg(long, action, help = "Minimum megabits per second for the internet.")]
     pub(super) min_mbps: Option<std::primitive::u32>,
 
+    // Minimum megabits per second for the cheapest internet column.
+    #[arg(
+        long,
+        default_value = "100",
+        help = "Minimum megabits per second for the cheapest internet column."
+    )]
+    pub(super) cheapest_internet_min_mbps: std::primitive::u32,
+
+    // Explain why every fetched announcement was included or rejected?
+    #[arg(
+        long,
+        action,
+        help = "Explain why every fetched announcement was included or rejected and write it to a report file?"
+    )]
+    pub(super) explain: bool,
+
+    // Write internet offers to JSON and CSV files?
+    #[arg(long, action, help = "Write internet offers to JSON and CSV files?")]
+    pub(super) export_internet_offers: bool,
+
+    // Exclude house if it does not have fixed broadband that has at least minimum megabits per second.
+    #[arg(
+        long,
+        action,
+        help = "Exclude house if it does not have fixed broadband that has at least minimum megabits per second."
+    )]
+    pub(super) require_internet: bool,
+
+    // Technologies that qualify as required fixed broadband.
+    #[arg(
+        long,
+        help = "Technologies that qualify as required fixed broadband, for example fiber or cable.",
+        requires = "require_internet"
+    )]
+    pub(super) require_internet_technologies: Vec<std::string::String>,
+
+    // Mobile network coverage API URL template.
+    #[arg(
+        long,
+        help = "Mobile network coverage API URL template with {latitude} and {longitude} placeholders.",
+        conflicts_with = "coverage_geojson"
+    )]
+    pub(super) coverage_url: Option<std::string::String>,
+
+    // Mobile network coverage GeoJSON file.
+    #[arg(
+        long,
+        help = "Mobile network coverage GeoJSON file, for example converted from Traficom coverage data."
+    )]
+    pub(super) coverage_geojson: Option<std::string::String>,
+
+    // Names of the coverage properties that have the network fields.
+    #[arg(
+        long,
+        value_parser = coverage_property,
+        help = "Name of the coverage API or GeoJSON property for network field given as FIELD=PROPERTY. FIELD is one of: OPERATOR, TECHNOLOGY, SPEED_CLASS or MBPS."
+    )]
+    pub(super) coverage_properties: Vec<(std::string::String, std::string::String)>,
+
+    // Cache mobile network coverage API result?
+    #[arg(
+        long,
+        action,
+        requires = "coverage_url",
+        help = "Cache mobile network coverage API result?"
+    )]
+    pub(super) cache_coverage: bool,
+
+    // Additional broadband provider.
+    #[arg(
+        long,
+        value_parser = broadband_provider,
+        help = "Additional broadband provider given as NAME=URL_TEMPLATE. URL_TEMPLATE can have {postal_code} and {street_address} placeholders."
+    )]
+    pub(super) broadband_providers: Vec<(std::string::String, std::string::String)>,
+
     // Exclude house if it's text data has one of these texts.
     #[arg(long, help = "Exclude house if it's text data has one of these texts.")]
     pub(super) exclude_texts: Vec<std::string::String>,
+
+    // Exclude house if it's text data matches one of these regular expressions.
+    #[arg(
+        long,
+        value_parser = text_pattern,
+        help = "Exclude house if it's text data matches one of these case insensitive regular expressions, for example: \\bremontti"
+    )]
+    pub(super) exclude_patterns: Vec<regex::Regex>,
+
+    // Require house text data to have these words in any inflected form.
+    #[arg(
+        long,
+        value_parser = inflected_text,
+        help = "Require house text data to have these words in any Finnish inflected form, for example sauna matches saunan and saunassa."
+    )]
+    pub(super) require_texts: Vec<regex::Regex>,
+
+    // Require house text data to match these regular expressions.
+    #[arg(
+        long,
+        value_parser = text_pattern,
+        help = "Require house text data to match these case insensitive regular expressions, for example: \\b(takka|varaava)\\b"
+    )]
+    pub(super) require_patterns: Vec<regex::Regex>,
+
+    // Are all or any of the required texts and patterns required?
+    #[arg(
+        long,
+        default_value="ALL",
+        value_parser = clap::builder::PossibleValuesParser::new(["ALL", "ANY"]),
+        help = "Are all or any of the required texts and patterns required? One of: ALL or ANY"
+    )]
+    pub(super) require_texts_mode: std::string::String,
+
+    // Exclude house if it's location is unknown even after geocoding.
+    #[arg(
+        long,
+        action,
+        help = "Exclude house if it's location is unknown even after geocoding."
+    )]
+    pub(super) exclude_unknown_location: bool,
+
+    // Calculate distances to nearest amenities from OpenStreetMap?
+    #[arg(
+        long,
+        action,
+        help = "Calculate distances to nearest amenities from OpenStreetMap?"
+    )]
+    pub(super) amenities: bool,
+
+    // Interpreter URL for self-hosted Overpass API.
+    #[arg(long, help = "Interpreter URL for self-hosted Overpass API.")]
+    pub(super) overpass_base_url: Option<std::string::String>,
+
+    // Local OpenStreetMap extract to search amenities from instead of Overpass API.
+    #[arg(
+        long,
+        help = "Path to local OpenStreetMap .osm.pbf extract to search amenities from instead of Overpass API.",
+        conflicts_with = "overpass_base_url"
+    )]
+    pub(super) osm_pbf_path: Option<std::string::String>,
+
+    // Exclude house if nearest amenity is further than this.
+    #[arg(
+        long,
+        value_parser = amenity_max_km,
+        help = "Exclude house if nearest amenity is further than this. Given as AMENITY=KM, where AMENITY is one of: GROCERY, SCHOOL, DAYCARE, TRAIN_STATION or HEALTH_CENTRE"
+    )]
+    pub(super) amenity_max_km: Vec<(crate::app::Amenity, std::primitive::f64)>,
+
+    // Share of the price financed with a mortgage.
+    #[arg(
+        long,
+        default_value = "90",
+        help = "Share (%) of the price financed with a mortgage."
+    )]
+    pub(super) loan_to_value_percent: std::primitive::f64,
+
+    // Yearly mortgage interest rate.
+    #[arg(long, default_value = "4", help = "Yearly mortgage interest rate (%).")]
+    pub(super) interest_rate_percent: std::primitive::f64,
+
+    // Mortgage term in years.
+    #[arg(long, default_value = "25", help = "Mortgage term in years.")]
+    pub(super) loan_term_years: std::primitive::u16,
+
+    // Property tax rates by municipality.
+    #[arg(
+        long,
+        value_parser = property_tax_rate,
+        help = "Yearly property tax rate (%) of the taxable value by municipality given as MUNICIPALITY=PERCENT."
+    )]
+    pub(super) property_tax_rates: Vec<(std::string::String, std::primitive::f64)>,
+
+    // Property tax rate for municipalities without own rate.
+    #[arg(
+        long,
+        default_value = "1",
+        help = "Yearly property tax rate (%) of the taxable value for municipalities without own rate."
+    )]
+    pub(super) property_tax_percent: std::primitive::f64,
+
+    // Commute cost per kilometer of the biking route, assumed to be close to driving distance.
+    #[arg(
+        long,
+        default_value = "0.25",
+        help = "Commute cost in euros per kilometer of biking route to location, assuming driving distance is about the same."
+    )]
+    pub(super) commute_euros_per_route_km: std::primitive::f64,
+
+    // Maximum estimated monthly cost of ownership.
+    #[arg(
+        long,
+        help = "Exclude house if it's estimated monthly cost of ownership in euros is more than this."
+    )]
+    pub(super) max_euros_per_month: Option<std::primitive::u32>,
+
+    // Budget for moving and renovation.
+    #[arg(
+        long,
+        default_value = "0",
+        help = "Budget in euros for moving and renovation."
+    )]
+    pub(super) moving_renovation_euros: std::primitive::u32,
+
+    // Sort results by.
+    #[arg(
+        long,
+        default_value="PRICE_PER_SQUARE_METER",
+        value_parser = clap::builder::PossibleValuesParser::new(
+            ["SCORE", "PRICE", "PRICE_PER_SQUARE_METER", "MONTHLY_COST", "DISTANCE", "TRANSIT"]
+        ),
+        help = "Sort results by. One of: SCORE, PRICE, PRICE_PER_SQUARE_METER, MONTHLY_COST, DISTANCE or TRANSIT"
+    )]
+    pub(super) sort_by: std::string::String,
+
+    // Format of the results file.
+    #[arg(
+        long,
+        default_value="CSV",
+        value_parser = clap::builder::PossibleValuesParser::new(["CSV", "JSON", "JSONL"]),
+        help = "Format of the results file. One of: CSV, JSON or JSONL"
+    )]
+    pub(super) output_format: std::string::String,
+
+    // Path to the results file.
+    #[arg(
+        long,
+        help = "Path to the results file, - for stdout. New file in the same directory as executable if not given."
+    )]
+    pub(super) output: Option<std::string::String>,
+
+    // Score weight for price per square meter of the house.
+    #[arg(
+        long,
+        default_value = "1",
+        help = "Score weight for price per square meter of the house."
+    )]
+    pub(super) weight_price_per_square_meter: std::primitive::f64,
+
+    // Score weight for commute time to location.
+    #[arg(
+        long,
+        default_value = "1",
+        help = "Score weight for commute time to location."
+    )]
+    pub(super) weight_commute: std::primitive::f64,
+
+    // Score weight for construction year.
+    #[arg(
+        long,
+        default_value = "1",
+        help = "Score weight for construction year."
+    )]
+    pub(super) weight_year: std::primitive::f64,
+
+    // Score weight for total floor area of the property.
+    #[arg(
+        long,
+        default_value = "1",
+        help = "Score weight for total floor area of the property."
+    )]
+    pub(super) weight_total_area: std::primitive::f64,
+
+    // Score weight for internet speed.
+    #[arg(long, default_value = "1", help = "Score weight for internet speed.")]
+    pub(super) weight_internet: std::primitive::f64,
+
+    // Score weight for energy class.
+    #[arg(long, default_value = "1", help = "Score weight for energy class.")]
+    pub(super) weight_energy_class: std::primitive::f64,
+
+    // Score weight for keywords.
+    #[arg(long, default_value = "1", help = "Score weight for keywords.")]
+    pub(super) weight_keywords: std::primitive::f64,
+
+    // Keywords in the announcement text that raise the score.
+    #[arg(long, help = "Keywords in the announcement text that raise the score.")]
+    pub(super) score_keywords: Vec<std::string::String>,
+
+    // Exclude house if filter expression is not true for it.
+    #[arg(
+        long,
+        value_parser = filter,
+        help = "Exclude house if filter expression is not true for it, for example: year >= 1990 && (heating == \"geothermal\" || price_per_m2 < 2500)"
+    )]
+    pub(super) filters: Vec<crate::filter::Expression>,
+}
+
+impl Args {
+    /// Telegram chat ids to send results to.
+    pub(crate) fn telegram_chat_ids(&self) -> std::vec::Vec<std::primitive::i64> {
+        let mut chat_ids: std::vec::Vec<std::primitive::i64> =
+            std::vec::Vec::<std::primitive::i64>::new();
+        if let Some(telegram_user_id) = self.telegram_user_id {
+            chat_ids.push(telegram_user_id as std::primitive::i64);
+        }
+        for chat_id in &self.telegram_chat_ids {
+            if !chat_ids.contains(chat_id) {
+                chat_ids.push(*chat_id);
+            }
+        }
+        chat_ids
+    }
+
+    /// Are results written to stdout?
+    /// Then other output is written to stderr, so that results can be piped.
+    pub(super) fn output_to_stdout(&self) -> std::primitive::bool {
+        self.output.as_deref() == Some("-")
+    }
+
+    /// Are there notifiers that are not tied to a Telegram chat?
+    pub(super) fn has_shared_notifiers(&self) -> std::primitive::bool {
+        !self.email_to.is_empty()
+            || !self.webhook_urls.is_empty()
+            || !self.discord_webhook_urls.is_empty()
+            || !self.slack_webhook_urls.is_empty()
+            || !self.ntfy_topics.is_empty()
+            || !self.matrix_room_ids.is_empty()
+    }
+
+    /// Remove notifiers that are not tied to a Telegram chat.
+    /// Used for searches with saved search of a chat.
+    pub(super) fn clear_shared_notifiers(&mut self) {
+        self.email_to.clear();
+        self.webhook_urls.clear();
+        self.discord_webhook_urls.clear();
+        self.slack_webhook_urls.clear();
+        self.ntfy_topics.clear();
+        self.matrix_room_ids.clear();
+    }
+}
+
+/// Parse broadband provider argument.
+///
+/// # Arguments
+/// * `value` - Argument value as NAME=URL_TEMPLATE.
+fn broadband_provider(
+    value: &std::primitive::str,
+) -> std::result::Result<(std::string::String, std::string::String), std::string::String> {
+    let (name, url_template) = value
+        .split_once('=')
+        .ok_or(format!("Expected NAME=URL_TEMPLATE, got: {}", value))?;
+    // Name is used as cache directory, so it can not have path separators or dots.
+    if name.is_empty()
+        || !name.chars().all(|character| {
+            character.is_ascii_alphanumeric() || character == '_' || character == '-'
+        })
+    {
+        return Err(format!(
+            "NAME can only have letters A-Z, numbers, underscores and hyphens, got: {}",
+            name
+        ));
+    }
+    Ok((name.to_string(), url_template.to_string()))
+}
+
+/// Parse coverage property argument.
+///
+/// # Arguments
+/// * `value` - Argument value as FIELD=PROPERTY.
+fn coverage_property(
+    value: &std::primitive::str,
+) -> std::result::Result<(std::string::String, std::string::String), std::string::String> {
+    let (field, property) = value
+        .split_once('=')
+        .ok_or(format!("Expected FIELD=PROPERTY, got: {}", value))?;
+    // Validate field name.
+    crate::coverage::Properties::default().set(field, property)?;
+    Ok((field.to_string(), property.to_string()))
+}
+
+/// Parse amenity maximum distance argument.
+///
+/// # Arguments
+/// * `value` - Argument value as AMENITY=KM.
+fn amenity_max_km(
+    value: &std::primitive::str,
+) -> std::result::Result<(crate::app::Amenity, std::primitive::f64), std::string::String> {
+    let (name, km) = value
+        .split_once('=')
+        .ok_or(format!("Expected AMENITY=KM, got: {}", value))?;
+    let amenity: crate::app::Amenity =
+        crate::app::Amenity::from_name(name).ok_or(format!("Unknown amenity: {}", name))?;
+    let km: std::primitive::f64 = km
+        .parse::<std::primitive::f64>()
+        .map_err(|error| format!("Invalid kilometers {}: {}", km, error))?;
+    if !km.is_finite() || km <= 0.0 {
+        return Err(format!("Kilometers must be a positive number, got: {}", km));
+    }
+    Ok((amenity, km))
+}
+
+/// Parse case insensitive regular expression argument.
+///
+/// # Arguments
+/// * `value` - Regular expression.
+fn text_pattern(
+    value: &std::primitive::str,
+) -> std::result::Result<regex::Regex, std::string::String> {
+    regex::RegexBuilder::new(value)
+        .case_insensitive(true)
+        .build()
+        .map_err(|error| error.to_string())
+}
+
+/// Longest ending after the stem and it's vowel, for example -issaan in saunoissaan.
+const INFLECTED_ENDING_MAX_CHARS: std::primitive::usize = 6;
+
+/// Parse text argument to regular expression that matches it's words in Finnish inflected forms.
+/// Every word must start and end at word boundary and can have an ending of at most six letters
+/// after the whole word, or after it's stem followed by the last vowel or it's plural form.
+/// Stem drops the last vowel and weakens double k, p or t,
+/// so for example sauna matches saunan and saunoissa, and katto matches katon but not katu.
+///
+/// # Arguments
+/// * `value` - Text with one or more words.
+fn inflected_text(
+    value: &std::primitive::str,
+) -> std::result::Result<regex::Regex, std::string::String> {
+    let words: std::vec::Vec<std::string::String> = value
+        .split_whitespace()
+        .map(|word| {
+            let word: std::string::String = word.to_lowercase();
+            let mut stem: std::string::String = word.clone();
+            let vowels: &std::primitive::str = match stem.chars().last() {
+                Some(_) if stem.chars().count() <= 3 => "",
+                Some('a') => "aoi",
+                Some('ä') => "äöi",
+                Some('i') => "ie",
+                Some('e') => "e",
+                Some('o') => "o",
+                Some('u') => "u",
+                Some('y') => "y",
+                Some('ö') => "ö",
+                _ => "",
+            };
+            if vowels.is_empty() {
+                return format!(
+                    r"\b{}\w{{0,{}}}\b",
+                    regex::escape(&word),
+                    INFLECTED_ENDING_MAX_CHARS
+                );
+            }
+            let _: std::option::Option<std::primitive::char> = stem.pop();
+            if stem.ends_with("kk") || stem.ends_with("pp") || stem.ends_with("tt") {
+                let _: std::option::Option<std::primitive::char> = stem.pop();
+            }
+            format!(
+                r"\b(?:{}|{}[{}])\w{{0,{}}}\b",
+                regex::escape(&word),
+                regex::escape(&stem),
+                vowels,
+                INFLECTED_ENDING_MAX_CHARS
+            )
+        })
+        .collect();
+    if words.is_empty() {
+        return Err("Expected at least one word".to_string());
+    }
+    text_pattern(&words.join(r"\s+"))
+}
+
+/// Parse filter expression argument.
+///
+/// # Arguments
+/// * `value` - Filter expression.
+fn filter(
+    value: &std::primitive::str,
+) -> std::result::Result<crate::filter::Expression, std::string::String> {
+    crate::filter::Expression::parse(value).map_err(|error| error.to_string())
+}
+
+/// Parse property tax rate argument.
+///
+/// # Arguments
+/// * `value` - Argument value as MUNICIPALITY=PERCENT.
+fn property_tax_rate(
+    value: &std::primitive::str,
+) -> std::result::Result<(std::string::String, std::primitive::f64), std::string::String> {
+    let (municipality, percent) = value
+        .split_once('=')
+        .ok_or(format!("Expected MUNICIPALITY=PERCENT, got: {}", value))?;
+    Ok((
+        municipality.to_lowercase(),
+        percent
+            .parse::<std::primitive::f64>()
+            .map_err(|error| format!("Invalid percent {}: {}", percent, error))?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    /// Does the inflected text match whole text?
+    ///
+    /// # Arguments
+    /// * `value` - Inflected text argument.
+    /// * `text` - Text to match.
+    fn matches(value: &std::primitive::str, text: &std::primitive::str) -> std::primitive::bool {
+        super::inflected_text(value)
+            .unwrap()
+            .find(text)
+            .is_some_and(|found| found.as_str() == text)
+    }
+
+    #[test]
+    fn sauna() {
+        for text in ["sauna", "Saunan", "saunassa", "saunoissa", "saunoissaan"] {
+            assert!(matches("sauna", text), "{}", text);
+        }
+        assert!(super::inflected_text("sauna")
+            .unwrap()
+            .find("höyrysauna")
+            .is_none());
+    }
+
+    #[test]
+    fn katto() {
+        for text in ["katto", "katon", "katolla", "katot", "katoissa"] {
+            assert!(matches("katto", text), "{}", text);
+        }
+        for text in ["katu", "kattila", "katsastus", "katiska"] {
+            assert!(
+                super::inflected_text("katto").unwrap().find(text).is_none(),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn multiple_words() {
+        for text in ["oma ranta", "omaa  rantaa"] {
+            assert!(matches("oma ranta", text), "{}", text);
+        }
+        // Other consonant gradations than double k, p or t are not matched.
+        assert!(super::inflected_text("oma ranta")
+            .unwrap()
+            .find("oman rannan")
+            .is_none());
+    }
+
+    #[test]
+    fn limits_ending() {
+        assert!(super::inflected_text("takka")
+            .unwrap()
+            .find("takkahuoneellinen")
+            .is_none());
+        assert!(super::inflected_text("   ").is_err());
+    }
 }
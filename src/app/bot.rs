@@ -0,0 +1,564 @@
+/// Run interactive Telegram bot until interrupted.
+/// Bot answers commands only from authorized users and runs searches on demand.
+///
+/// # Arguments
+/// * `args` - Application arguments used as default search for every user.
+pub(crate) async fn bot(args: super::Args) -> std::result::Result<(), super::Error> {
+    let token: std::string::String = match &args.telegram_bot_token {
+        Some(telegram_bot_token) => telegram_bot_token.clone(),
+        // Should not happen if clap is configured and working correctly.
+        None => std::panic!("--telegram-bot was given but not --telegram-bot-token!"),
+    };
+    let mut authorized_user_ids: std::vec::Vec<std::primitive::u64> =
+        args.telegram_authorized_user_ids.clone();
+    if let Some(telegram_user_id) = args.telegram_user_id {
+        authorized_user_ids.push(telegram_user_id);
+    }
+    let store: crate::store::Store = crate::store::Store::new(super::SAVED_SEARCHES)?;
+    let state: std::sync::Arc<super::BotState> = std::sync::Arc::new(super::BotState {
+        saved_searches: tokio::sync::Mutex::new(store.load()?),
+        args,
+        token: token.clone(),
+        authorized_user_ids,
+        store,
+        listings: crate::store::Store::new(super::LISTINGS)?,
+        running_since: tokio::sync::Mutex::new(None),
+        last_runs: tokio::sync::Mutex::new(std::collections::HashMap::<
+            std::primitive::i64,
+            super::LastRun,
+        >::new()),
+    });
+
+    let bot: teloxide::prelude::Bot = teloxide::prelude::Bot::new(token);
+    let _: teloxide::types::True = teloxide::requests::Requester::set_my_commands(
+        &bot,
+        <super::BotCommand as teloxide::utils::command::BotCommands>::bot_commands(),
+    )
+    .await?;
+    teloxide::dispatching::Dispatcher::builder(
+        bot,
+        teloxide::dptree::entry()
+            .branch(
+                <teloxide::types::Update as teloxide::dispatching::UpdateFilterExt<_>>::filter_message()
+                    .branch(teloxide::filter_command::<super::BotCommand, _>().endpoint(handle))
+                    .branch(teloxide::dptree::endpoint(handle_note)),
+            )
+            .branch(
+                <teloxide::types::Update as teloxide::dispatching::UpdateFilterExt<_>>::filter_callback_query()
+                    .endpoint(handle_callback),
+            ),
+    )
+    .dependencies(teloxide::dptree::deps![state])
+    .enable_ctrlc_handler()
+    .build()
+    .dispatch()
+    .await;
+    Ok(())
+}
+
+/// Handle bot command.
+///
+/// # Arguments
+/// * `bot` - Telegram bot.
+/// * `message` - Message that had the command.
+/// * `command` - Command to handle.
+/// * `state` - Shared state of the bot.
+async fn handle(
+    bot: teloxide::prelude::Bot,
+    message: teloxide::prelude::Message,
+    command: super::BotCommand,
+    state: std::sync::Arc<super::BotState>,
+) -> teloxide::prelude::ResponseResult<()> {
+    let user_id: std::primitive::u64 = match &message.from {
+        Some(user) => user.id.0,
+        None => return Ok(()),
+    };
+    // Saved search and results are per chat, so group chats share them.
+    let chat_id: std::primitive::i64 = message.chat.id.0;
+    let reply: std::string::String = if !state.authorized_user_ids.contains(&user_id) {
+        "You are not authorized to use this bot.".to_string()
+    } else {
+        match command {
+            super::BotCommand::Help => {
+                <super::BotCommand as teloxide::utils::command::BotCommands>::descriptions()
+                    .to_string()
+            }
+            super::BotCommand::Search => {
+                // Updates from the same chat are handled one at a time,
+                // so search runs in it's own task to keep answering other commands like /status.
+                let bot: teloxide::prelude::Bot = bot.clone();
+                let state: std::sync::Arc<super::BotState> = std::sync::Arc::clone(&state);
+                let chat: teloxide::types::ChatId = message.chat.id;
+                // Dropping the handle detaches the task.
+                std::mem::drop(tokio::task::spawn(async move {
+                    let reply: std::string::String = search(&state, chat_id).await;
+                    if let Err(error) =
+                        teloxide::requests::Requester::send_message(&bot, chat, reply).await
+                    {
+                        eprintln!("Failed to send search reply: {}", error);
+                    }
+                }));
+                "Searching...".to_string()
+            }
+            super::BotCommand::Last => match state.last_runs.lock().await.get(&chat_id) {
+                Some(last_run) => {
+                    for last_message in &last_run.messages {
+                        let _: teloxide::prelude::Message =
+                            teloxide::requests::Requester::send_message(
+                                &bot,
+                                message.chat.id,
+                                last_message,
+                            )
+                            .await?;
+                    }
+                    format!(
+                        "Found {} at {}.",
+                        last_run.messages.len(),
+                        last_run.at.format("%Y-%m-%d %H:%M")
+                    )
+                }
+                None => "You have not searched yet, use /search.".to_string(),
+            },
+            super::BotCommand::Status => status(&state, chat_id).await,
+            super::BotCommand::Favourites => favourites(&state),
+            super::BotCommand::Filters => filters(&args(&state, chat_id).await),
+            super::BotCommand::PriceMax(price_max) => {
+                let price_max: &std::primitive::str = price_max.trim();
+                if price_max.is_empty() {
+                    save(&state, chat_id, |saved_search| {
+                        saved_search.price_max = None
+                    })
+                    .await
+                } else {
+                    match price_max.parse::<std::primitive::u32>() {
+                        Ok(price_max) => {
+                            save(&state, chat_id, |saved_search| {
+                                saved_search.price_max = Some(price_max)
+                            })
+                            .await
+                        }
+                        Err(error) => format!("Invalid max price {}: {}", price_max, error),
+                    }
+                }
+            }
+            super::BotCommand::Email(email_to) => {
+                let email_to: std::vec::Vec<std::string::String> = email_to
+                    .split_whitespace()
+                    .map(|email| email.to_string())
+                    .collect();
+                if state.args.smtp_host.is_none() {
+                    "Email is not configured, start with --smtp-host.".to_string()
+                } else {
+                    save(&state, chat_id, |saved_search| {
+                        saved_search.email_to = if email_to.is_empty() {
+                            None
+                        } else {
+                            Some(email_to)
+                        }
+                    })
+                    .await
+                }
+            }
+            super::BotCommand::Cities(cities) => {
+                let cities: std::vec::Vec<std::string::String> = cities
+                    .split_whitespace()
+                    .map(|city| city.to_string())
+                    .collect();
+                save(&state, chat_id, |saved_search| {
+                    saved_search.cities = if cities.is_empty() {
+                        None
+                    } else {
+                        Some(cities)
+                    }
+                })
+                .await
+            }
+        }
+    };
+    let _: teloxide::prelude::Message =
+        teloxide::requests::Requester::send_message(&bot, message.chat.id, reply).await?;
+    Ok(())
+}
+
+/// Prefix of the message asking for a note, reply to it is saved as the note.
+const NOTE_PREFIX: &std::primitive::str = "Note for ";
+
+/// Handle inline keyboard button press on a listing.
+///
+/// # Arguments
+/// * `bot` - Telegram bot.
+/// * `query` - Callback query from the button.
+/// * `state` - Shared state of the bot.
+async fn handle_callback(
+    bot: teloxide::prelude::Bot,
+    query: teloxide::types::CallbackQuery,
+    state: std::sync::Arc<super::BotState>,
+) -> teloxide::prelude::ResponseResult<()> {
+    let answer: std::string::String = if !state.authorized_user_ids.contains(&query.from.id.0) {
+        "You are not authorized to use this bot.".to_string()
+    } else {
+        match query.data.as_deref().and_then(super::ListingAction::parse) {
+            Some((action, key)) => match update_listing(&state, &key, action).await {
+                Ok((url, answer)) => {
+                    if action == super::ListingAction::Note {
+                        let _: teloxide::prelude::Message =
+                            teloxide::payloads::SendMessageSetters::reply_markup(
+                                teloxide::requests::Requester::send_message(
+                                    &bot,
+                                    query.from.id,
+                                    format!("{}{}:", NOTE_PREFIX, url),
+                                ),
+                                teloxide::types::ForceReply::new(),
+                            )
+                            .await?;
+                    }
+                    answer
+                }
+                Err(error) => error,
+            },
+            None => "Unknown button.".to_string(),
+        }
+    };
+    let _: teloxide::types::True = teloxide::payloads::AnswerCallbackQuerySetters::text(
+        teloxide::requests::Requester::answer_callback_query(&bot, query.id),
+        answer,
+    )
+    .await?;
+    Ok(())
+}
+
+/// Apply action to the listing and store it.
+///
+/// # Arguments
+/// * `state` - Shared state of the bot.
+/// * `key` - Key for the listing URL.
+/// * `action` - Action to apply.
+///
+/// # Returns
+/// URL of the listing and answer to the user, or error message.
+async fn update_listing(
+    state: &super::BotState,
+    key: &std::primitive::str,
+    action: super::ListingAction,
+) -> std::result::Result<(std::string::String, std::string::String), std::string::String> {
+    let mut listings: std::collections::HashMap<std::string::String, super::Listing> = state
+        .listings
+        .load()
+        .map_err(|error| format!("Failed to load listings: {}", error))?;
+    let url: std::string::String =
+        super::Listing::url(&listings, key).ok_or("Unknown listing.".to_string())?;
+    let listing: &mut super::Listing = listings.entry(url.clone()).or_default();
+    let answer: &'static std::primitive::str = match action {
+        super::ListingAction::Favourite => {
+            listing.favourite = !listing.favourite;
+            if listing.favourite {
+                "Added to favourites."
+            } else {
+                "Removed from favourites."
+            }
+        }
+        super::ListingAction::Hide => {
+            listing.hidden = !listing.hidden;
+            if listing.hidden {
+                "Hidden, will not be notified again."
+            } else {
+                "No longer hidden."
+            }
+        }
+        super::ListingAction::Note => return Ok((url, "Reply with the note.".to_string())),
+    };
+    state
+        .listings
+        .save(&listings)
+        .map_err(|error| format!("Failed to save listings: {}", error))?;
+    Ok((url, answer.to_string()))
+}
+
+/// Handle reply to the note question and save it as note for the listing.
+///
+/// # Arguments
+/// * `bot` - Telegram bot.
+/// * `message` - Message that is not a command.
+/// * `state` - Shared state of the bot.
+async fn handle_note(
+    bot: teloxide::prelude::Bot,
+    message: teloxide::prelude::Message,
+    state: std::sync::Arc<super::BotState>,
+) -> teloxide::prelude::ResponseResult<()> {
+    let user_id: std::primitive::u64 = match &message.from {
+        Some(user) => user.id.0,
+        None => return Ok(()),
+    };
+    let url: std::string::String = match message
+        .reply_to_message()
+        .and_then(|question| question.text())
+        .and_then(|question| question.strip_prefix(NOTE_PREFIX))
+        .and_then(|question| question.strip_suffix(':'))
+    {
+        Some(url) => url.to_string(),
+        None => return Ok(()),
+    };
+    let reply: std::string::String = if !state.authorized_user_ids.contains(&user_id) {
+        "You are not authorized to use this bot.".to_string()
+    } else {
+        let note: std::option::Option<std::string::String> = match message.text() {
+            Some(text) if !text.trim().is_empty() && text.trim() != "-" => {
+                Some(text.trim().to_string())
+            }
+            _ => None,
+        };
+        match state
+            .listings
+            .load::<std::collections::HashMap<std::string::String, super::Listing>>()
+        {
+            Ok(mut listings) => {
+                listings.entry(url).or_default().note = note;
+                match state.listings.save(&listings) {
+                    Ok(()) => "Note saved.".to_string(),
+                    Err(error) => format!("Failed to save listings: {}", error),
+                }
+            }
+            Err(error) => format!("Failed to load listings: {}", error),
+        }
+    };
+    let _: teloxide::prelude::Message =
+        teloxide::requests::Requester::send_message(&bot, message.chat.id, reply).await?;
+    Ok(())
+}
+
+/// Arguments with saved search of the chat applied.
+///
+/// # Arguments
+/// * `state` - Shared state of the bot.
+/// * `chat_id` - Telegram chat id.
+async fn args(state: &super::BotState, chat_id: std::primitive::i64) -> super::Args {
+    let mut args: super::Args = state.args.clone();
+    if let Some(saved_search) = state.saved_searches.lock().await.get(&chat_id) {
+        saved_search.apply(&mut args);
+    }
+    args
+}
+
+/// Update and store saved search of the chat.
+///
+/// # Arguments
+/// * `state` - Shared state of the bot.
+/// * `chat_id` - Telegram chat id.
+/// * `update` - Update to the saved search.
+async fn save<F: FnOnce(&mut super::SavedSearch)>(
+    state: &super::BotState,
+    chat_id: std::primitive::i64,
+    update: F,
+) -> std::string::String {
+    let mut saved_searches: tokio::sync::MutexGuard<
+        '_,
+        std::collections::HashMap<std::primitive::i64, super::SavedSearch>,
+    > = state.saved_searches.lock().await;
+    update(saved_searches.entry(chat_id).or_default());
+    if let Err(error) = state.store.save(&*saved_searches) {
+        return format!("Failed to save search: {}", error);
+    }
+    drop(saved_searches);
+    format!("Saved.\n{}", filters(&args(state, chat_id).await))
+}
+
+/// Run search with saved search of the chat and send results to the chat.
+/// Error is also sent to admin chat if given.
+///
+/// # Arguments
+/// * `state` - Shared state of the bot.
+/// * `chat_id` - Telegram chat id.
+async fn search(state: &super::BotState, chat_id: std::primitive::i64) -> std::string::String {
+    {
+        let mut running_since: tokio::sync::MutexGuard<
+            '_,
+            std::option::Option<chrono::DateTime<chrono::Local>>,
+        > = state.running_since.lock().await;
+        if let Some(since) = *running_since {
+            return format!(
+                "Search is already running since {}, try again later.",
+                since.format("%H:%M")
+            );
+        }
+        *running_since = Some(chrono::Local::now());
+    }
+    let mut args: super::Args = state.args.clone();
+    // Results of search from a chat are sent only to the chat and email addresses of its saved search.
+    args.clear_shared_notifiers();
+    if let Some(saved_search) = state.saved_searches.lock().await.get(&chat_id) {
+        saved_search.apply(&mut args);
+    }
+    let notifiers: std::vec::Vec<std::sync::Arc<dyn super::Notifier>> =
+        super::notifiers(&args, std::vec![chat_id]);
+    let mut output: super::Output = super::Output::new(&args.output_format, args.output.as_deref());
+    let result: std::result::Result<std::vec::Vec<super::Result>, super::Error> =
+        match super::run(args, notifiers, &mut output).await {
+            Ok(results) => match output.finish() {
+                Ok(path) => {
+                    if let Some(path) = path {
+                        eprintln!("Wrote file: {}", path);
+                    }
+                    Ok(results)
+                }
+                Err(error) => Err(error.into()),
+            },
+            Err(error) => Err(error),
+        };
+    *state.running_since.lock().await = None;
+    match result {
+        Ok(results) => {
+            let _: std::option::Option<super::LastRun> = state.last_runs.lock().await.insert(
+                chat_id,
+                super::LastRun {
+                    at: chrono::Local::now(),
+                    messages: results.iter().map(|result| result.message()).collect(),
+                },
+            );
+            "Search done.".to_string()
+        }
+        Err(error) => {
+            let message: std::string::String = format!("Got error: {}", error);
+            if let Some(telegram_admin_chat_id) = state.args.telegram_admin_chat_id {
+                if let Err(error) = crate::telegram::Telegram::new(
+                    &state.token,
+                    std::vec![telegram_admin_chat_id],
+                    false,
+                    state.args.telegram_digest_top,
+                )
+                .send_message(&message)
+                .await
+                {
+                    eprintln!("Failed to send error to admin chat: {}", error);
+                }
+            }
+            message
+        }
+    }
+}
+
+/// Status of the bot for the chat.
+///
+/// # Arguments
+/// * `state` - Shared state of the bot.
+/// * `chat_id` - Telegram chat id.
+async fn status(state: &super::BotState, chat_id: std::primitive::i64) -> std::string::String {
+    let mut message: std::string::String = match *state.running_since.lock().await {
+        Some(since) => format!("Searching since {}.", since.format("%Y-%m-%d %H:%M")),
+        None => "Idle.".to_string(),
+    };
+    match state.last_runs.lock().await.get(&chat_id) {
+        Some(last_run) => message.push_str(&format!(
+            "\nYour last search was at {} and found {}.",
+            last_run.at.format("%Y-%m-%d %H:%M"),
+            last_run.messages.len()
+        )),
+        None => message.push_str("\nYou have not searched yet."),
+    }
+    message
+}
+
+/// Favourite listings with their last known price and note.
+///
+/// # Arguments
+/// * `state` - Shared state of the bot.
+fn favourites(state: &super::BotState) -> std::string::String {
+    let listings: std::collections::HashMap<std::string::String, super::Listing> =
+        match state.listings.load() {
+            Ok(listings) => listings,
+            Err(error) => return format!("Failed to load listings: {}", error),
+        };
+    let mut lines: std::vec::Vec<std::string::String> = listings
+        .iter()
+        .filter(|(_, listing)| listing.favourite)
+        .map(|(url, listing)| {
+            let mut line: std::string::String = url.clone();
+            if let Some(euros) = listing.euros {
+                line.push_str(&format!("\n\t{} €", euros));
+            }
+            if let Some(note) = &listing.note {
+                line.push_str(&format!("\n\t📝 {}", note));
+            }
+            line
+        })
+        .collect();
+    if lines.is_empty() {
+        return "No favourites yet, use ⭐ button under a house.".to_string();
+    }
+    lines.sort();
+    lines.join("\n")
+}
+
+/// Filters of the search.
+///
+/// # Arguments
+/// * `args` - Application arguments with saved search applied.
+fn filters(args: &super::Args) -> std::string::String {
+    let mut lines: std::vec::Vec<std::string::String> = std::vec![
+        format!("Publishing time: {}", args.publishing_time_search_criteria),
+        format!(
+            "Max price: {}",
+            match args.price_max {
+                Some(price_max) => format!(
+                    "{} €{}",
+                    price_max,
+                    if args.price_max_total { " total" } else { "" }
+                ),
+                None => "any".to_string(),
+            }
+        ),
+        format!(
+            "Cities: {}",
+            if args.cities.is_empty() {
+                "any".to_string()
+            } else {
+                args.cities.join(", ")
+            }
+        ),
+    ];
+    if let Some(house_min_square_meters) = args.house_min_square_meters {
+        lines.push(format!("Min area: {} m²", house_min_square_meters));
+    }
+    if let Some(max_distance_km) = args.max_distance_km {
+        lines.push(format!("Max distance: {} km", max_distance_km));
+    }
+    if let Some(min_mbps) = args.min_mbps {
+        lines.push(format!(
+            "Min internet: {} Mbit/s{}",
+            min_mbps,
+            if args.require_internet {
+                " required"
+            } else {
+                ""
+            }
+        ));
+    }
+    for (amenity, max_km) in &args.amenity_max_km {
+        lines.push(format!("Max {}: {:.1} km", amenity.title(), max_km));
+    }
+    if !args.exclude_texts.is_empty() {
+        lines.push(format!("Exclude texts: {}", args.exclude_texts.join(", ")));
+    }
+    if !args.require_texts.is_empty() || !args.require_patterns.is_empty() {
+        lines.push(format!(
+            "Require {} of: {}",
+            args.require_texts_mode.to_lowercase(),
+            args.require_texts
+                .iter()
+                .chain(args.require_patterns.iter())
+                .map(|require_text| require_text.as_str())
+                .collect::<std::vec::Vec<&std::primitive::str>>()
+                .join(", ")
+        ));
+    }
+    for filter in &args.filters {
+        lines.push(format!("Filter: {}", filter));
+    }
+    if let Some(max_euros_per_month) = args.max_euros_per_month {
+        lines.push(format!("Max monthly cost: {} €/kk", max_euros_per_month));
+    }
+    lines.push(format!("Sort by: {}", args.sort_by));
+    if !args.email_to.is_empty() {
+        lines.push(format!("Email to: {}", args.email_to.join(", ")));
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,32 @@
+/// Commands for the interactive Telegram bot.
+#[derive(teloxide::macros::BotCommands, Debug, Clone)]
+#[command(
+    rename_rule = "snake_case",
+    description = "These commands are supported:"
+)]
+pub(crate) enum BotCommand {
+    #[command(description = "show this help.")]
+    Help,
+    #[command(description = "search houses now with your saved search.")]
+    Search,
+    #[command(description = "show results of your last search again.")]
+    Last,
+    #[command(description = "show whether search is running and when it was last run.")]
+    Status,
+    #[command(description = "show favourite houses with their notes.")]
+    Favourites,
+    #[command(description = "show filters of your saved search.")]
+    Filters,
+    #[command(
+        description = "set max price in euros for your saved search, without value to reset."
+    )]
+    PriceMax(std::string::String),
+    #[command(
+        description = "set cities separated by spaces for your saved search, without value to reset."
+    )]
+    Cities(std::string::String),
+    #[command(
+        description = "set email addresses separated by spaces to also send results to, without value to reset."
+    )]
+    Email(std::string::String),
+}
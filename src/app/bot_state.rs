@@ -0,0 +1,14 @@
+/// Shared state of the interactive Telegram bot.
+pub(crate) struct BotState {
+    pub(super) args: super::Args,
+    pub(super) token: std::string::String,
+    pub(super) authorized_user_ids: std::vec::Vec<std::primitive::u64>,
+    pub(super) store: crate::store::Store,
+    pub(super) listings: crate::store::Store,
+    pub(super) saved_searches:
+        tokio::sync::Mutex<std::collections::HashMap<std::primitive::i64, super::SavedSearch>>,
+    pub(super) running_since:
+        tokio::sync::Mutex<std::option::Option<chrono::DateTime<chrono::Local>>>,
+    pub(super) last_runs:
+        tokio::sync::Mutex<std::collections::HashMap<std::primitive::i64, super::LastRun>>,
+}
@@ -0,0 +1,56 @@
+/// Decision whether to include house as one of the options.
+#[derive(Debug, Clone)]
+pub(crate) enum Decision {
+    Include,
+    /// Rejected by filter with measured value compared to threshold.
+    Reject {
+        filter: &'static std::primitive::str,
+        value: std::string::String,
+        threshold: std::string::String,
+    },
+}
+
+impl Decision {
+    /// Reject house.
+    ///
+    /// # Arguments
+    /// * `filter` - Name of the filter that rejected the house.
+    /// * `value` - Measured value.
+    /// * `threshold` - Threshold that the value did not pass.
+    pub(super) fn reject<V: std::string::ToString, T: std::string::ToString>(
+        filter: &'static std::primitive::str,
+        value: V,
+        threshold: T,
+    ) -> Self {
+        Decision::Reject {
+            filter,
+            value: value.to_string(),
+            threshold: threshold.to_string(),
+        }
+    }
+
+    /// Name of the filter that rejected the house, None if included.
+    pub(super) fn filter(&self) -> std::option::Option<&'static std::primitive::str> {
+        match self {
+            Decision::Include => None,
+            Decision::Reject { filter, .. } => Some(filter),
+        }
+    }
+}
+
+impl std::fmt::Display for Decision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Decision::Include => write!(f, "included"),
+            Decision::Reject {
+                filter,
+                value,
+                threshold,
+            } => write!(
+                f,
+                "rejected by {}: {} (threshold {})",
+                filter, value, threshold
+            ),
+        }
+    }
+}
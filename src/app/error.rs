@@ -4,9 +4,29 @@ pub(crate) enum Error {
     RegexError(crate::etuovi::RegexError),
     RequestError(crate::client::RequestError),
     TeloxideError(teloxide::RequestError),
+    Email(crate::email::Error),
     OpenRouteServiceError(crate::open_route_service::Error),
+    InvalidHeaderValue(reqwest::header::InvalidHeaderValue),
     TokioTaskJoinError(tokio::task::JoinError),
     IOError(std::io::Error),
+    LocationNotFound(std::string::String),
+    /// Errors of multiple notifiers.
+    NotifierErrors(std::vec::Vec<Error>),
+}
+impl Error {
+    /// Result of notifying multiple notifiers, error if any of them failed.
+    ///
+    /// # Arguments
+    /// * `errors` - Errors of the failed notifiers.
+    pub(super) fn from_notifier_errors(
+        mut errors: std::vec::Vec<Error>,
+    ) -> std::result::Result<(), Error> {
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(Error::NotifierErrors(errors)),
+        }
+    }
 }
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -15,9 +35,21 @@ impl std::fmt::Display for Error {
             Error::RegexError(e) => write!(f, "Regex error:\n{}", e),
             Error::RequestError(e) => write!(f, "Request error:\n{}", e),
             Error::TeloxideError(e) => write!(f, "Teloxide error:\n{}", e),
+            Error::Email(e) => write!(f, "Email error:\n{}", e),
             Error::OpenRouteServiceError(e) => write!(f, "OpenRouteService error:\n{}", e),
+            Error::InvalidHeaderValue(e) => write!(f, "Invalid header value:\n{}", e),
             Error::TokioTaskJoinError(e) => write!(f, "Tokio task join error:\n{}", e),
             Error::IOError(e) => write!(f, "IO error:\n{}", e),
+            Error::LocationNotFound(address) => write!(f, "Location not found for:\n{}", address),
+            Error::NotifierErrors(errors) => write!(
+                f,
+                "Notifier errors:\n{}",
+                errors
+                    .iter()
+                    .map(|error| error.to_string())
+                    .collect::<std::vec::Vec<std::string::String>>()
+                    .join("\n\n")
+            ),
         }
     }
 }
@@ -41,11 +73,21 @@ impl From<teloxide::RequestError> for Error {
         Error::TeloxideError(err)
     }
 }
+impl From<crate::email::Error> for Error {
+    fn from(err: crate::email::Error) -> Self {
+        Error::Email(err)
+    }
+}
 impl From<crate::open_route_service::Error> for Error {
     fn from(err: crate::open_route_service::Error) -> Self {
         Error::OpenRouteServiceError(err)
     }
 }
+impl From<reqwest::header::InvalidHeaderValue> for Error {
+    fn from(err: reqwest::header::InvalidHeaderValue) -> Self {
+        Error::InvalidHeaderValue(err)
+    }
+}
 impl From<tokio::task::JoinError> for Error {
     fn from(error: tokio::task::JoinError) -> Self {
         Error::TokioTaskJoinError(error)
@@ -3,14 +3,13 @@ use super::Internet;
 
 pub(crate) struct House<A: super::Announcement> {
     pub(self) announcement: A,
-    pub(self) location_comparison: std::option::Option<longitude::Location>,
-    pub(self) open_route_service_token: std::option::Option<std::string::String>,
-    pub(self) cache_elisa_fixed_broadband_products: std::primitive::bool,
+    pub(self) settings: std::sync::Arc<super::HouseSettings>,
     pub(self) biking_km_to_location: std::option::Option<std::primitive::u16>,
-    pub(self) house_min_square_meters: std::option::Option<std::primitive::u16>,
-    pub(self) max_distance_km: std::option::Option<std::primitive::u16>,
-    pub(self) min_mbps: std::option::Option<std::primitive::u32>,
-    pub(self) exclude_texts: std::vec::Vec<std::string::String>,
+    pub(self) transit_minutes_to_location: std::option::Option<std::primitive::u16>,
+    pub(self) location: std::option::Option<longitude::Location>,
+    pub(self) km_to_amenities: std::option::Option<crate::app::AmenityKm>,
+    pub(self) internet_offers:
+        std::option::Option<std::option::Option<std::vec::Vec<super::InternetOffer>>>,
 }
 
 impl<A: super::Announcement> House<A> {
@@ -18,90 +17,204 @@ impl<A: super::Announcement> House<A> {
     ///
     /// # Arguments
     /// * `announcement` - Announcement for the house.
-    /// * `location_comparison` - Location for the location.
-    /// * `open_route_service_token` - Open Route Service key.
-    /// * `cache_elisa_fixed_broadband_products` - Use cache when getting Elisa fixed broadband products?
-    /// * `house_min_square_meters` - Optional minimum area in square meters of the house.
-    /// * `max_distance_km` - Optional maximum distance in kilometers to the location.
-    /// * `min_mbps` - Optional minimum megabits per second for the internet.
-    /// * `exclude_texts` - Exclude house if it's text data has one of these texts.
-    pub(super) fn new(
-        announcement: A,
-        location_comparison: std::option::Option<longitude::Location>,
-        open_route_service_token: std::option::Option<std::string::String>,
-        cache_elisa_fixed_broadband_products: std::primitive::bool,
-        house_min_square_meters: std::option::Option<std::primitive::u16>,
-        max_distance_km: std::option::Option<std::primitive::u16>,
-        min_mbps: std::option::Option<std::primitive::u32>,
-        exclude_texts: std::vec::Vec<std::string::String>,
-    ) -> Self {
+    /// * `settings` - Settings for checking the house.
+    pub(super) fn new(announcement: A, settings: std::sync::Arc<super::HouseSettings>) -> Self {
         Self {
             announcement,
-            location_comparison,
-            open_route_service_token,
-            cache_elisa_fixed_broadband_products,
+            settings,
             biking_km_to_location: None,
-            house_min_square_meters,
-            max_distance_km,
-            min_mbps,
-            exclude_texts,
+            transit_minutes_to_location: None,
+            location: None,
+            km_to_amenities: None,
+            internet_offers: None,
         }
     }
 
+    /// Geocode location for the house from it's address.
+    ///
+    /// # Arguments
+    /// * `postal_code` - Postal code for the house.
+    pub(self) async fn geocode(
+        &self,
+        postal_code: &std::primitive::str,
+    ) -> std::result::Result<
+        std::option::Option<longitude::Location>,
+        crate::open_route_service::Error,
+    > {
+        if let Some(open_route_service_token) = &self.settings.open_route_service_token {
+            return Ok(
+                crate::open_route_service::OpenRouteService::new(open_route_service_token)?
+                    .geocode(&format!(
+                        "{}, {}",
+                        self.announcement.street_address(),
+                        postal_code
+                    ))
+                    .await?,
+            );
+        }
+        Ok(None)
+    }
+
+    /// Location for the house.
+    /// Geocoded from the address if announcement does not have it.
+    pub(self) async fn location(
+        &mut self,
+    ) -> std::result::Result<std::option::Option<longitude::Location>, super::Error> {
+        if self.location.is_none() {
+            self.location = self.announcement.location();
+            if self.location.is_none() && self.settings.open_route_service_token.is_some() {
+                let postal_code: std::string::String = self.announcement.postal_code().await?;
+                self.location = self.geocode(&postal_code).await?;
+            }
+        }
+        Ok(self.location.clone())
+    }
+
     /// Distance to location directly.
-    pub(self) fn distance_to_location(&self) -> std::option::Option<longitude::Distance> {
-        if let Some(location_comparison) = &self.location_comparison {
-            if let Some(location_house) = &self.announcement.location() {
-                return Some(location_house.distance(location_comparison));
+    pub(self) async fn distance_to_location(
+        &mut self,
+    ) -> std::result::Result<std::option::Option<longitude::Distance>, super::Error> {
+        if let Some(location_comparison) = self.settings.location_comparison.clone() {
+            if let Some(location_house) = &self.location().await? {
+                return Ok(Some(location_house.distance(&location_comparison)));
             }
         }
 
-        return None;
+        Ok(None)
     }
 
-    /// Internets as strings.
+    /// Query internet offers.
+    /// Queries Elisa and additional broadband providers concurrently.
     ///
     /// # Arguments
     /// * `postal_code` - Postal code for the house.
-    pub(self) async fn internet_strings(
+    ///
+    /// # Returns
+    /// None if no provider knew the address, so internet availability is unknown.
+    pub(self) async fn query_internet_offers(
         &self,
         postal_code: &std::primitive::str,
-    ) -> std::result::Result<std::vec::Vec<std::string::String>, crate::client::JSONError> {
-        let mut internet_strings: std::vec::Vec<std::string::String> =
-            std::vec::Vec::<std::string::String>::new();
-        for elisa_internet in crate::elisa::Elisa::new(
-            postal_code,
-            &self.announcement.street_address(),
-            self.cache_elisa_fixed_broadband_products,
-        )
-        .await?
-        .internets()
-        {
-            if elisa_internet.check_mbps(self.min_mbps) {
-                internet_strings.push(elisa_internet.to_str());
+    ) -> std::result::Result<std::option::Option<std::vec::Vec<super::InternetOffer>>, super::Error>
+    {
+        let mut handles: std::vec::Vec<
+            tokio::task::JoinHandle<
+                std::result::Result<
+                    std::option::Option<std::vec::Vec<super::InternetOffer>>,
+                    crate::client::JSONError,
+                >,
+            >,
+        > = std::vec::Vec::<
+            tokio::task::JoinHandle<
+                std::result::Result<
+                    std::option::Option<std::vec::Vec<super::InternetOffer>>,
+                    crate::client::JSONError,
+                >,
+            >,
+        >::new();
+        let street_address: std::string::String = self.announcement.street_address();
+        let min_mbps: std::option::Option<std::primitive::u32> = self.settings.min_mbps;
+
+        let elisa_postal_code: std::string::String = postal_code.to_string();
+        let elisa_street_address: std::string::String = street_address.clone();
+        let cache_elisa_fixed_broadband_products: std::primitive::bool =
+            self.settings.cache_elisa_fixed_broadband_products;
+        handles.push(tokio::task::spawn(async move {
+            Ok(crate::elisa::Elisa::new(
+                &elisa_postal_code,
+                &elisa_street_address,
+                cache_elisa_fixed_broadband_products,
+            )
+            .await?
+            .map(|elisa| {
+                elisa
+                    .internets()
+                    .iter()
+                    .filter(|internet| internet.check_mbps(min_mbps))
+                    .map(|internet| internet.offer())
+                    .collect()
+            }))
+        }));
+
+        for broadband_provider in &self.settings.broadband_providers {
+            let broadband_provider: std::sync::Arc<crate::broadband::Broadband> =
+                std::sync::Arc::clone(broadband_provider);
+            let postal_code: std::string::String = postal_code.to_string();
+            let street_address: std::string::String = street_address.clone();
+            handles.push(tokio::task::spawn(async move {
+                // Failing additional provider is unknown, so offers of other providers are still used.
+                return match broadband_provider
+                    .internets(&postal_code, &street_address)
+                    .await
+                {
+                    Ok(internets) => Ok(Some(
+                        internets
+                            .iter()
+                            .filter(|internet| internet.check_mbps(min_mbps))
+                            .map(|internet| internet.offer())
+                            .collect(),
+                    )),
+                    Err(error) => {
+                        eprintln!(
+                            "Failed to get internet products from {} for {} {}: {}",
+                            broadband_provider.name(),
+                            street_address,
+                            postal_code,
+                            error
+                        );
+                        Ok(None)
+                    }
+                };
+            }));
+        }
+
+        let mut internet_offers: std::option::Option<std::vec::Vec<super::InternetOffer>> = None;
+        for handle in handles {
+            if let Some(provider_internet_offers) = handle.await?? {
+                internet_offers
+                    .get_or_insert_with(std::vec::Vec::<super::InternetOffer>::new)
+                    .extend(provider_internet_offers);
             }
         }
-        return Ok(internet_strings);
+        Ok(internet_offers)
+    }
+
+    /// Internet offers.
+    /// None if internet availability is unknown.
+    pub(self) async fn internet_offers(
+        &mut self,
+    ) -> std::result::Result<std::option::Option<std::vec::Vec<super::InternetOffer>>, super::Error>
+    {
+        if self.internet_offers.is_none() {
+            let postal_code: std::string::String = self.announcement.postal_code().await?;
+            self.internet_offers = Some(self.query_internet_offers(&postal_code).await?);
+        }
+        Ok(self.internet_offers.clone().flatten())
+    }
+
+    /// Best mobile network at the house.
+    pub(self) async fn mobile_network(
+        &mut self,
+    ) -> std::result::Result<std::option::Option<crate::coverage::Network>, super::Error> {
+        if self.settings.coverage.is_some() {
+            if let Some(location) = self.location().await? {
+                if let Some(coverage) = &self.settings.coverage {
+                    return Ok(coverage.best(location).await?);
+                }
+            }
+        }
+        Ok(None)
     }
 
     /// Biking distance in kilometers to location.
     pub(self) async fn biking_km_to_location(
         &mut self,
-    ) -> std::result::Result<
-        std::option::Option<std::primitive::u16>,
-        crate::open_route_service::Error,
-    > {
-        if self.biking_km_to_location.is_none() {
-            if let Some(location_comparison) = &self.location_comparison {
-                if let Some(open_route_service_token) = &self.open_route_service_token {
-                    if let Some(location) = &self.announcement.location() {
-                        self.biking_km_to_location = Some(
-                            crate::open_route_service::OpenRouteService::new(
-                                open_route_service_token,
-                            )?
-                            .biking_km(location.clone(), location_comparison.clone())
-                            .await?,
-                        );
+    ) -> std::result::Result<std::option::Option<std::primitive::u16>, super::Error> {
+        if self.biking_km_to_location.is_none() && self.settings.routing.is_some() {
+            if let Some(location_comparison) = self.settings.location_comparison.clone() {
+                if let Some(location) = self.location().await? {
+                    if let Some(routing) = &self.settings.routing {
+                        self.biking_km_to_location =
+                            Some(routing.biking_km(location, location_comparison).await?);
                     }
                 }
             }
@@ -109,85 +222,490 @@ impl<A: super::Announcement> House<A> {
         return Ok(self.biking_km_to_location);
     }
 
-    /// Include house as one of the options?
-    pub(self) async fn include(
+    /// Public transport journey time in minutes to location.
+    pub(self) async fn transit_minutes_to_location(
+        &mut self,
+    ) -> std::result::Result<std::option::Option<std::primitive::u16>, super::Error> {
+        if self.transit_minutes_to_location.is_none() && self.settings.transit.is_some() {
+            if let Some(location_comparison) = self.settings.location_comparison.clone() {
+                if let Some(location) = self.location().await? {
+                    if let Some(transit) = &self.settings.transit {
+                        self.transit_minutes_to_location = transit
+                            .transit_minutes(location, location_comparison)
+                            .await?;
+                    }
+                }
+            }
+        }
+        Ok(self.transit_minutes_to_location)
+    }
+
+    /// Straight line distances in kilometers to nearest amenities.
+    pub(self) async fn km_to_amenities(
+        &mut self,
+    ) -> std::result::Result<crate::app::AmenityKm, super::Error> {
+        if self.km_to_amenities.is_none() && self.settings.amenities.is_some() {
+            if let Some(location) = self.location().await? {
+                if let Some(amenities) = &self.settings.amenities {
+                    self.km_to_amenities = Some(amenities.nearest_km(location).await?);
+                }
+            }
+        }
+        Ok(self.km_to_amenities.clone().unwrap_or_default())
+    }
+
+    /// Estimated monthly cost of ownership.
+    /// None if price is unknown.
+    pub(self) async fn monthly_cost(
         &mut self,
-    ) -> std::result::Result<std::primitive::bool, super::Error> {
+    ) -> std::result::Result<std::option::Option<super::MonthlyCost>, super::Error> {
+        let euros: std::primitive::u32 = match self.announcement.euros() {
+            Some(euros) => euros,
+            None => return Ok(None),
+        };
+        let commute_km: std::option::Option<std::primitive::u16> =
+            match self.biking_km_to_location().await? {
+                Some(biking_km_to_location) => Some(biking_km_to_location),
+                None => self
+                    .distance_to_location()
+                    .await?
+                    .map(|distance_to_location| {
+                        distance_to_location.kilometers().ceil() as std::primitive::u16
+                    }),
+            };
+        let cheapest_internet_euros_per_month: std::option::Option<std::primitive::f32> =
+            super::InternetOffer::cheapest_euros_per_month(
+                &self.internet_offers().await?.unwrap_or_default(),
+                self.settings.cheapest_internet_min_mbps,
+            );
+        let heating: std::option::Option<std::string::String> = self.announcement.heating().await?;
+        let energy_class: std::option::Option<std::string::String> =
+            self.announcement.energy_class().await?;
+        Ok(Some(super::MonthlyCost::estimate(
+            &self.settings.monthly_cost_settings,
+            super::MonthlyCostValues {
+                euros,
+                square_meters: self
+                    .announcement
+                    .square_meters_house()
+                    .or(self.announcement.square_meters_total()),
+                municipality: self.announcement.municipality(),
+                heating,
+                energy_class,
+                internet_euros_per_month: cheapest_internet_euros_per_month,
+                commute_km,
+            },
+        )))
+    }
+
+    /// Estimated one-off purchase costs.
+    /// None if price is unknown.
+    pub(self) fn purchase_cost(&self) -> std::option::Option<super::PurchaseCost> {
+        self.announcement.euros().map(|euros| {
+            super::PurchaseCost::estimate(&self.settings.purchase_cost_settings, euros)
+        })
+    }
+
+    /// Weighted score for the house.
+    pub(self) async fn score(&mut self) -> std::result::Result<super::Score, super::Error> {
+        // Values are not looked up for components that do not affect the score.
+        let best_mbps: std::option::Option<std::primitive::u32> =
+            if self.settings.score_weights.internet == 0.0 {
+                None
+            } else {
+                super::InternetOffer::best_mbps(&self.internet_offers().await?.unwrap_or_default())
+            };
+        let text: std::option::Option<std::string::String> = if self.settings.score_weights.keywords
+            == 0.0
+            || self.settings.score_weights.keyword_texts.is_empty()
+        {
+            None
+        } else {
+            Some(self.announcement.text().await?)
+        };
+        let (transit_minutes_to_location, biking_km_to_location): (
+            std::option::Option<std::primitive::u16>,
+            std::option::Option<std::primitive::u16>,
+        ) = if self.settings.score_weights.commute == 0.0 {
+            (None, None)
+        } else {
+            (
+                self.transit_minutes_to_location().await?,
+                self.biking_km_to_location().await?,
+            )
+        };
+        let energy_class: std::option::Option<std::string::String> =
+            if self.settings.score_weights.energy_class == 0.0 {
+                None
+            } else {
+                self.announcement.energy_class().await?
+            };
+        Ok(super::Score::new(
+            &self.settings.score_weights,
+            super::ScoreValues {
+                euros_per_square_meter: match self.announcement.euros() {
+                    Some(euros) => match self.announcement.square_meters_house() {
+                        Some(square_meters_house) if square_meters_house > 0 => {
+                            Some(euros / square_meters_house as std::primitive::u32)
+                        }
+                        _ => None,
+                    },
+                    None => None,
+                },
+                transit_minutes: transit_minutes_to_location,
+                biking_km: biking_km_to_location,
+                year: self.announcement.year(),
+                square_meters_total: self.announcement.square_meters_total(),
+                best_mbps,
+                energy_class,
+                text,
+            },
+        ))
+    }
+
+    /// Value of the field for filter expressions.
+    ///
+    /// # Arguments
+    /// * `field` - Field to get value for.
+    pub(self) async fn filter_value(
+        &mut self,
+        field: crate::filter::Field,
+    ) -> std::result::Result<crate::filter::Value, super::Error> {
+        let euros: std::option::Option<std::primitive::u32> = self.announcement.euros();
+        Ok(match field {
+            crate::filter::Field::Price => crate::filter::Value::number(euros),
+            crate::filter::Field::PricePerSquareMeter => crate::filter::Value::number(
+                match (euros, self.announcement.square_meters_house()) {
+                    (Some(euros), Some(square_meters_house)) if square_meters_house > 0 => Some(
+                        euros as std::primitive::f64 / square_meters_house as std::primitive::f64,
+                    ),
+                    _ => None,
+                },
+            ),
+            crate::filter::Field::Area => {
+                crate::filter::Value::number(self.announcement.square_meters_house())
+            }
+            crate::filter::Field::TotalArea => {
+                crate::filter::Value::number(self.announcement.square_meters_total())
+            }
+            crate::filter::Field::Year => crate::filter::Value::number(self.announcement.year()),
+            crate::filter::Field::Floors => {
+                crate::filter::Value::number(self.announcement.floors().await?)
+            }
+            crate::filter::Field::DistanceKm => crate::filter::Value::number(
+                self.distance_to_location()
+                    .await?
+                    .map(|distance_to_location| distance_to_location.kilometers()),
+            ),
+            crate::filter::Field::BikingKm => {
+                crate::filter::Value::number(self.biking_km_to_location().await?)
+            }
+            crate::filter::Field::TransitMinutes => {
+                crate::filter::Value::number(self.transit_minutes_to_location().await?)
+            }
+            crate::filter::Field::Mbps => crate::filter::Value::number(
+                super::InternetOffer::best_mbps(&self.internet_offers().await?.unwrap_or_default())
+                    .map(|mbps| mbps as std::primitive::f64),
+            ),
+            crate::filter::Field::MonthlyCost => crate::filter::Value::number(
+                self.monthly_cost()
+                    .await?
+                    .map(|monthly_cost| monthly_cost.total()),
+            ),
+            crate::filter::Field::OneOffCost => crate::filter::Value::number(
+                self.purchase_cost()
+                    .map(|purchase_cost| purchase_cost.total()),
+            ),
+            crate::filter::Field::Score => {
+                crate::filter::Value::number(Some(self.score().await?.total()))
+            }
+            crate::filter::Field::Heating => {
+                crate::filter::Value::text(self.announcement.heating().await?.map(|heating| {
+                    // For example GEOTHERMAL_HEATING to geothermal.
+                    heating
+                        .to_lowercase()
+                        .trim_end_matches("_heating")
+                        .replace('_', " ")
+                }))
+            }
+            crate::filter::Field::EnergyClass => {
+                crate::filter::Value::text(self.announcement.energy_class().await?)
+            }
+            crate::filter::Field::Municipality => {
+                crate::filter::Value::text(self.announcement.municipality())
+            }
+        })
+    }
+
+    /// Decide whether to include house as one of the options.
+    pub(self) async fn decision(&mut self) -> std::result::Result<super::Decision, super::Error> {
         // Check area.
-        if let Some(house_min_square_meters) = self.house_min_square_meters {
+        if let Some(house_min_square_meters) = self.settings.house_min_square_meters {
             if let Some(square_meters_house) = self.announcement.square_meters_house() {
                 if square_meters_house < house_min_square_meters {
-                    return Ok(false);
+                    return Ok(super::Decision::reject(
+                        "house_min_square_meters",
+                        format!("{} m² house", square_meters_house),
+                        format!("{} m²", house_min_square_meters),
+                    ));
                 }
             } else if let Some(square_meters_total) = self.announcement.square_meters_total() {
                 if square_meters_total < house_min_square_meters {
-                    return Ok(false);
+                    return Ok(super::Decision::reject(
+                        "house_min_square_meters",
+                        format!("{} m² total", square_meters_total),
+                        format!("{} m²", house_min_square_meters),
+                    ));
+                }
+            }
+        }
+
+        // Check total cash outlay.
+        if let Some(price_max_total) = self.settings.price_max_total {
+            if let Some(euros) = self.announcement.euros() {
+                if let Some(purchase_cost) = self.purchase_cost() {
+                    let euros_total: std::primitive::f64 =
+                        euros as std::primitive::f64 + purchase_cost.total();
+                    if euros_total > price_max_total as std::primitive::f64 {
+                        return Ok(super::Decision::reject(
+                            "price_max_total",
+                            format!("{:.0} €", euros_total),
+                            format!("{} €", price_max_total),
+                        ));
+                    }
                 }
             }
         }
 
+        // Check location.
+        if self.settings.exclude_unknown_location && self.location().await?.is_none() {
+            return Ok(super::Decision::reject(
+                "exclude_unknown_location",
+                "unknown location",
+                "known location",
+            ));
+        }
+
         // Check distance.
-        if let Some(max_distance_km) = self.max_distance_km {
-            if let Some(distance_to_location) = self.distance_to_location() {
-                if max_distance_km < distance_to_location.kilometers().ceil() as std::primitive::u16
-                {
-                    return Ok(false);
+        if let Some(max_distance_km) = self.settings.max_distance_km {
+            if let Some(distance_to_location) = self.distance_to_location().await? {
+                let km_to_location_straight: std::primitive::u16 =
+                    distance_to_location.kilometers().ceil() as std::primitive::u16;
+                if max_distance_km < km_to_location_straight {
+                    return Ok(super::Decision::reject(
+                        "max_distance_km",
+                        format!("{} km straight", km_to_location_straight),
+                        format!("{} km", max_distance_km),
+                    ));
                 }
             }
             if let Some(biking_km_to_location) = self.biking_km_to_location().await? {
                 if max_distance_km < biking_km_to_location {
-                    return Ok(false);
+                    return Ok(super::Decision::reject(
+                        "max_distance_km",
+                        format!("{} km biking", biking_km_to_location),
+                        format!("{} km", max_distance_km),
+                    ));
+                }
+            }
+        }
+
+        // Check amenities, only if location is known.
+        if !self.settings.amenity_max_km.is_empty() && self.location().await?.is_some() {
+            let km_to_amenities: std::collections::HashMap<
+                crate::app::Amenity,
+                std::primitive::f64,
+            > = self.km_to_amenities().await?;
+            for (amenity, max_km) in &self.settings.amenity_max_km {
+                match km_to_amenities.get(amenity) {
+                    Some(km) if km <= max_km => {}
+                    km => {
+                        return Ok(super::Decision::reject(
+                            "amenity_max_km",
+                            format!(
+                                "{} {}",
+                                amenity.title(),
+                                match km {
+                                    Some(km) => format!("{:.1} km", km),
+                                    None => "not found".to_string(),
+                                }
+                            ),
+                            format!("{:.1} km", max_km),
+                        ))
+                    }
                 }
             }
         }
 
         // Check texts.
-        if !self.exclude_texts.is_empty() {
-            let text_lowercase: std::string::String =
-                self.announcement.text().await?.to_lowercase();
-            for invalid_text in &self.exclude_texts {
+        if !self.settings.exclude_texts.is_empty()
+            || !self.settings.exclude_patterns.is_empty()
+            || !self.settings.require_texts.is_empty()
+        {
+            let text: std::string::String = self.announcement.text().await?;
+            let text_lowercase: std::string::String = text.to_lowercase();
+            for invalid_text in &self.settings.exclude_texts {
                 if text_lowercase.contains(invalid_text) {
-                    return Ok(false);
+                    return Ok(super::Decision::reject(
+                        "exclude_texts",
+                        format!("has {}", invalid_text),
+                        "none of the texts",
+                    ));
+                }
+            }
+            for exclude_pattern in &self.settings.exclude_patterns {
+                if exclude_pattern.is_match(&text) {
+                    return Ok(super::Decision::reject(
+                        "exclude_patterns",
+                        format!("matches {}", exclude_pattern.as_str()),
+                        "none of the patterns",
+                    ));
+                }
+            }
+            if !self.settings.require_texts.is_empty() {
+                let matches: std::primitive::usize = self
+                    .settings
+                    .require_texts
+                    .iter()
+                    .filter(|require_text| require_text.is_match(&text))
+                    .count();
+                if (self.settings.require_all_texts && matches < self.settings.require_texts.len())
+                    || matches == 0
+                {
+                    return Ok(super::Decision::reject(
+                        "require_texts",
+                        format!("{}/{} matched", matches, self.settings.require_texts.len()),
+                        if self.settings.require_all_texts {
+                            "all"
+                        } else {
+                            "any"
+                        },
+                    ));
+                }
+            }
+        }
+
+        // Check internet after the cheap checks, because it is expensive.
+        // Filter expressions and monthly cost are checked after it, because they can use the internet offers.
+        if self.settings.require_internet {
+            let min_mbps: std::option::Option<std::primitive::u32> = self.settings.min_mbps;
+            match self.internet_offers().await? {
+                None => {
+                    return Ok(super::Decision::reject(
+                        "require_internet",
+                        "unknown internet availability",
+                        "qualifying fixed broadband",
+                    ))
+                }
+                Some(internet_offers) => {
+                    if !internet_offers.iter().any(|internet_offer| {
+                        internet_offer
+                            .qualifies(min_mbps, &self.settings.require_internet_technologies)
+                    }) {
+                        return Ok(super::Decision::reject(
+                            "require_internet",
+                            format!(
+                                "best {} Mbit/s",
+                                super::InternetOffer::best_mbps(&internet_offers).unwrap_or(0)
+                            ),
+                            format!(
+                                "{} Mbit/s{}",
+                                min_mbps.unwrap_or(0),
+                                if self.settings.require_internet_technologies.is_empty() {
+                                    "".to_string()
+                                } else {
+                                    format!(
+                                        " {}",
+                                        self.settings.require_internet_technologies.join("/")
+                                    )
+                                }
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        // Check filter expressions, only getting values for fields they use.
+        for filter in self.settings.filters.clone() {
+            let mut values: std::collections::HashMap<crate::filter::Field, crate::filter::Value> =
+                std::collections::HashMap::<crate::filter::Field, crate::filter::Value>::new();
+            for field in filter.fields() {
+                let _: std::option::Option<crate::filter::Value> =
+                    values.insert(field, self.filter_value(field).await?);
+            }
+            if !filter.evaluate(&values) {
+                return Ok(super::Decision::reject(
+                    "filters",
+                    filter
+                        .fields()
+                        .iter()
+                        .map(|field| {
+                            format!(
+                                "{}={}",
+                                field.name(),
+                                values
+                                    .get(field)
+                                    .cloned()
+                                    .unwrap_or(crate::filter::Value::Unknown)
+                            )
+                        })
+                        .collect::<std::vec::Vec<std::string::String>>()
+                        .join(", "),
+                    filter,
+                ));
+            }
+        }
+
+        // Check monthly cost, after internet because it uses the internet offers.
+        if let Some(max_euros_per_month) = self.settings.max_euros_per_month {
+            if let Some(monthly_cost) = self.monthly_cost().await? {
+                if monthly_cost.total() > max_euros_per_month as std::primitive::f64 {
+                    return Ok(super::Decision::reject(
+                        "max_euros_per_month",
+                        format!("{:.0} €/kk", monthly_cost.total()),
+                        format!("{} €/kk", max_euros_per_month),
+                    ));
                 }
             }
         }
 
-        return Ok(true);
+        Ok(super::Decision::Include)
+    }
+
+    /// Outcome for the house with result if it is included.
+    pub(super) async fn outcome(&mut self) -> std::result::Result<super::Outcome, super::Error> {
+        let decision: super::Decision = self.decision().await?;
+        Ok(super::Outcome {
+            url: self.announcement.url(),
+            euros: self.announcement.euros(),
+            result: match decision {
+                super::Decision::Include => Some(self.result().await?),
+                super::Decision::Reject { .. } => None,
+            },
+            decision,
+        })
     }
 
     /// Result for the house.
-    ///
-    /// # Arguments
-    /// * `postal_code` - Postal code for the house.
-    pub(super) async fn result(
-        &mut self,
-    ) -> std::result::Result<std::option::Option<super::Result>, super::Error> {
-        if !self.include().await? {
-            return Ok(None);
-        }
+    pub(self) async fn result(&mut self) -> std::result::Result<super::Result, super::Error> {
         let euros: std::option::Option<u32> = self.announcement.euros();
         let square_meters_house: std::option::Option<u16> = self.announcement.square_meters_house();
         let square_meters_total: std::option::Option<u16> = self.announcement.square_meters_total();
-        let postal_code: std::string::String = self.announcement.postal_code().await?;
-        Ok(Some(super::Result::new(
-            self.announcement.url().clone(),
-            match euros {
-                Some(euros) => Some(euros / 1000),
-                None => None,
-            },
-            self.announcement.floors().await?,
+        let internet_offers: std::option::Option<std::vec::Vec<super::InternetOffer>> =
+            self.internet_offers().await?;
+        Ok(super::Result {
+            url: self.announcement.url().clone(),
+            euros,
+            floors: self.announcement.floors().await?,
             square_meters_house,
-            match euros {
-                Some(euros) => match square_meters_house {
-                    Some(square_meters_house) => {
-                        Some(euros / square_meters_house as std::primitive::u32)
-                    }
-                    None => None,
-                },
+            euros_per_square_meter_house: match euros {
+                Some(euros) => square_meters_house
+                    .map(|square_meters_house| euros / square_meters_house as std::primitive::u32),
                 None => None,
             },
             square_meters_total,
-            match euros {
+            euros_per_square_meter_total: match euros {
                 Some(euros) => match square_meters_total {
                     Some(square_meters_total) => {
                         Some(euros / square_meters_total as std::primitive::u32)
@@ -196,15 +714,35 @@ impl<A: super::Announcement> House<A> {
                 },
                 None => None,
             },
-            match self.distance_to_location() {
-                Some(distance_to_location) => {
-                    Some(distance_to_location.kilometers().ceil() as std::primitive::u16)
+            km_to_location_straight: self.distance_to_location().await?.map(
+                |distance_to_location| {
+                    distance_to_location.kilometers().ceil() as std::primitive::u16
+                },
+            ),
+            km_to_location_biking: self.biking_km_to_location().await?,
+            minutes_to_location_transit: self.transit_minutes_to_location().await?,
+            year: self.announcement.year(),
+            km_to_amenities: self.km_to_amenities().await?,
+            best_mbps: super::InternetOffer::best_mbps(
+                &internet_offers.clone().unwrap_or_default(),
+            ),
+            cheapest_internet_euros_per_month: super::InternetOffer::cheapest_euros_per_month(
+                &internet_offers.clone().unwrap_or_default(),
+                self.settings.cheapest_internet_min_mbps,
+            ),
+            internet_offers,
+            mobile_network: self.mobile_network().await?,
+            monthly_cost: self.monthly_cost().await?,
+            purchase_cost: self.purchase_cost(),
+            score: self.score().await?,
+            address: match self.announcement.municipality() {
+                Some(municipality) => {
+                    format!("{}, {}", self.announcement.street_address(), municipality)
                 }
-                None => None,
+                None => self.announcement.street_address(),
             },
-            self.biking_km_to_location().await?,
-            self.announcement.year(),
-            self.internet_strings(&postal_code).await?,
-        )))
+            photo_url: self.announcement.photo_url().await?,
+            location: self.location().await?,
+        })
     }
 }
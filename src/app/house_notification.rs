@@ -0,0 +1,23 @@
+/// Found house formatted for notifiers.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct HouseNotification {
+    pub(crate) url: std::string::String,
+    pub(crate) address: std::string::String,
+    /// Plain text message.
+    pub(crate) message: std::string::String,
+    /// HTML formatted message.
+    pub(crate) message_html: std::string::String,
+    /// HTML formatted one line summary.
+    pub(crate) summary_html: std::string::String,
+    /// HTML table row with same columns as CSV.
+    pub(crate) row_html: std::string::String,
+    pub(crate) photo_url: std::option::Option<std::string::String>,
+    /// Location is not stored for queued houses, because they are sent as digest.
+    #[serde(skip)]
+    pub(crate) location: std::option::Option<longitude::Location>,
+    /// Result record of the house as JSON, with the same schema as JSON output.
+    pub(crate) json: serde_json::Value,
+    /// Sent immediately even during quiet hours.
+    pub(crate) urgent: std::primitive::bool,
+}
@@ -0,0 +1,56 @@
+/// Settings for checking houses and forming their results.
+/// Built once from application arguments and shared by every house.
+pub(crate) struct HouseSettings {
+    /// Location to compare against.
+    pub(super) location_comparison: std::option::Option<longitude::Location>,
+    /// OpenRouteService authorization token: https://openrouteservice.org/sign-up/
+    pub(super) open_route_service_token: std::option::Option<std::string::String>,
+    /// Routing provider for biking distance.
+    pub(super) routing: std::option::Option<std::sync::Arc<dyn super::Routing>>,
+    /// Digitransit client for public transport journey time.
+    pub(super) transit: std::option::Option<std::sync::Arc<crate::digitransit::Digitransit>>,
+    /// Use cache when getting Elisa fixed broadband products?
+    pub(super) cache_elisa_fixed_broadband_products: std::primitive::bool,
+    /// Minimum area in square meters of the house.
+    pub(super) house_min_square_meters: std::option::Option<std::primitive::u16>,
+    /// Maximum distance in kilometers to the location.
+    pub(super) max_distance_km: std::option::Option<std::primitive::u16>,
+    /// Minimum megabits per second for the internet.
+    pub(super) min_mbps: std::option::Option<std::primitive::u32>,
+    /// Exclude house if it's text data has one of these lowercase texts.
+    pub(super) exclude_texts: std::vec::Vec<std::string::String>,
+    /// Exclude house if it's location is unknown even after geocoding?
+    pub(super) exclude_unknown_location: std::primitive::bool,
+    /// Exclude house if it's text data matches one of these regular expressions.
+    pub(super) exclude_patterns: std::vec::Vec<regex::Regex>,
+    /// Require house text data to match these regular expressions.
+    pub(super) require_texts: std::vec::Vec<regex::Regex>,
+    /// Require all of the required texts instead of any of them?
+    pub(super) require_all_texts: std::primitive::bool,
+    /// Source for distances to nearest amenities.
+    pub(super) amenities: std::option::Option<std::sync::Arc<dyn super::Amenities>>,
+    /// Maximum distances in kilometers to nearest amenities.
+    pub(super) amenity_max_km: std::vec::Vec<(crate::app::Amenity, std::primitive::f64)>,
+    /// Additional broadband providers to query with Elisa.
+    pub(super) broadband_providers: std::vec::Vec<std::sync::Arc<crate::broadband::Broadband>>,
+    /// Minimum megabits per second for the cheapest internet.
+    pub(super) cheapest_internet_min_mbps: std::primitive::u32,
+    /// Exclude house if it does not have qualifying fixed broadband?
+    pub(super) require_internet: std::primitive::bool,
+    /// Lowercase technologies that qualify as fixed broadband, empty for any.
+    pub(super) require_internet_technologies: std::vec::Vec<std::string::String>,
+    /// Mobile network coverage.
+    pub(super) coverage: std::option::Option<std::sync::Arc<crate::coverage::Coverage>>,
+    /// Settings for estimating monthly cost of ownership.
+    pub(super) monthly_cost_settings: super::MonthlyCostSettings,
+    /// Maximum estimated monthly cost of ownership in euros.
+    pub(super) max_euros_per_month: std::option::Option<std::primitive::u32>,
+    /// Settings for estimating one-off purchase costs.
+    pub(super) purchase_cost_settings: super::PurchaseCostSettings,
+    /// Maximum total cash outlay including one-off purchase costs.
+    pub(super) price_max_total: std::option::Option<std::primitive::u32>,
+    /// Weights for scoring the house.
+    pub(super) score_weights: super::ScoreWeights,
+    /// Exclude house if any of these filter expressions is not true for it.
+    pub(super) filters: std::vec::Vec<crate::filter::Expression>,
+}
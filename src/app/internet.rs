@@ -1,14 +1,25 @@
 /// Internet trait.
 pub(crate) trait Internet {
+    /// Name of the provider.
+    fn provider(&self) -> std::string::String;
+
     /// Name of the internet product.
     fn name(&self) -> std::string::String;
 
+    /// Technology of the internet product, for example fiber or cable.
+    fn technology(&self) -> std::string::String;
+
     /// Price in euros per month.
     fn euros_per_month(&self) -> std::primitive::f32;
 
-    /// Speed in megabits per second.
+    /// Download speed in megabits per second.
     fn mbps(&self) -> std::primitive::u32;
 
+    /// Upload speed in megabits per second, if known.
+    fn mbps_up(&self) -> std::option::Option<std::primitive::u32> {
+        None
+    }
+
     /// When the internet is delivered.
     fn delivery_date(&self) -> std::string::String;
 
@@ -30,14 +41,16 @@ pub(crate) trait Internet {
         };
     }
 
-    /// Transform to string.
-    fn to_str(&self) -> std::string::String {
-        format!(
-            "{} ({}-): {:.2} €/kk, {:.0} Mbit/s",
-            self.name(),
-            self.delivery_date(),
-            self.euros_per_month(),
-            self.mbps(),
-        )
+    /// Transform to offer.
+    fn offer(&self) -> super::InternetOffer {
+        super::InternetOffer {
+            provider: self.provider(),
+            name: self.name(),
+            technology: self.technology(),
+            mbps_down: self.mbps(),
+            mbps_up: self.mbps_up(),
+            euros_per_month: self.euros_per_month(),
+            delivery_date: self.delivery_date(),
+        }
     }
 }
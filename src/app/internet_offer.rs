@@ -0,0 +1,72 @@
+/// Internet offer for the house.
+#[derive(Debug, Clone)]
+pub(crate) struct InternetOffer {
+    pub(crate) provider: std::string::String,
+    pub(crate) name: std::string::String,
+    pub(crate) technology: std::string::String,
+    pub(crate) mbps_down: std::primitive::u32,
+    pub(crate) mbps_up: std::option::Option<std::primitive::u32>,
+    pub(crate) euros_per_month: std::primitive::f32,
+    pub(crate) delivery_date: std::string::String,
+}
+
+impl InternetOffer {
+    /// Best available download speed in megabits per second.
+    ///
+    /// # Arguments
+    /// * `offers` - Internet offers.
+    pub(crate) fn best_mbps(offers: &[Self]) -> std::option::Option<std::primitive::u32> {
+        offers.iter().map(|offer| offer.mbps_down).max()
+    }
+
+    /// Cheapest price in euros per month for offer that has at least given download speed.
+    ///
+    /// # Arguments
+    /// * `offers` - Internet offers.
+    /// * `min_mbps` - Minimum download speed in megabits per second.
+    pub(crate) fn cheapest_euros_per_month(
+        offers: &[Self],
+        min_mbps: std::primitive::u32,
+    ) -> std::option::Option<std::primitive::f32> {
+        offers
+            .iter()
+            .filter(|offer| min_mbps <= offer.mbps_down)
+            .map(|offer| offer.euros_per_month)
+            .min_by(|a, b| a.total_cmp(b))
+    }
+
+    /// Does the offer qualify as required fixed broadband?
+    /// Unknown speed of 0 does not qualify if minimum is given.
+    ///
+    /// # Arguments
+    /// * `min_mbps` - Optional minimum download speed in megabits per second.
+    /// * `technologies` - Lowercase technologies that qualify, empty for any.
+    pub(crate) fn qualifies(
+        &self,
+        min_mbps: std::option::Option<std::primitive::u32>,
+        technologies: &[std::string::String],
+    ) -> std::primitive::bool {
+        if let Some(min_mbps) = min_mbps {
+            if self.mbps_down < min_mbps {
+                return false;
+            }
+        }
+        technologies.is_empty() || technologies.contains(&self.technology.to_lowercase())
+    }
+
+    /// Transform to string.
+    pub(crate) fn to_str(&self) -> std::string::String {
+        format!(
+            "{}: {} ({}, {}-): {:.2} €/kk, {}",
+            self.provider,
+            self.name,
+            self.technology,
+            self.delivery_date,
+            self.euros_per_month,
+            match self.mbps_up {
+                Some(mbps_up) => format!("{}/{} Mbit/s", self.mbps_down, mbps_up),
+                None => format!("{} Mbit/s", self.mbps_down),
+            },
+        )
+    }
+}
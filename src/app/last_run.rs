@@ -0,0 +1,6 @@
+/// Last search run for a Telegram bot user.
+#[derive(Debug, Clone)]
+pub(crate) struct LastRun {
+    pub(super) at: chrono::DateTime<chrono::Local>,
+    pub(super) messages: std::vec::Vec<std::string::String>,
+}
@@ -0,0 +1,35 @@
+/// Triage state of a listing, stored locally by listing URL.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub(crate) struct Listing {
+    pub(super) favourite: std::primitive::bool,
+    pub(super) hidden: std::primitive::bool,
+    pub(super) note: std::option::Option<std::string::String>,
+    /// Price in euros when listing was last seen.
+    pub(super) euros: std::option::Option<std::primitive::u32>,
+}
+
+/// Name of the store for listings.
+pub(super) const LISTINGS: &std::primitive::str = "listings";
+
+impl Listing {
+    /// Short key for the listing URL that fits into Telegram callback data.
+    ///
+    /// # Arguments
+    /// * `url` - Listing URL.
+    pub(super) fn key(url: &std::primitive::str) -> std::string::String {
+        format!("{:x}", <sha2::Sha256 as sha2::Digest>::digest(url))[..16].to_string()
+    }
+
+    /// Find listing URL for the key.
+    ///
+    /// # Arguments
+    /// * `listings` - Listings by URL.
+    /// * `key` - Key for the listing URL.
+    pub(super) fn url(
+        listings: &std::collections::HashMap<std::string::String, Self>,
+        key: &std::primitive::str,
+    ) -> std::option::Option<std::string::String> {
+        listings.keys().find(|url| Self::key(url) == key).cloned()
+    }
+}
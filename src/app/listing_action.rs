@@ -0,0 +1,62 @@
+/// Action for a listing from Telegram inline keyboard button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ListingAction {
+    Favourite,
+    Hide,
+    Note,
+}
+
+impl ListingAction {
+    /// All actions in the order of the buttons.
+    pub(super) const ALL: [ListingAction; 3] = [
+        ListingAction::Favourite,
+        ListingAction::Hide,
+        ListingAction::Note,
+    ];
+
+    /// Name of the action in callback data.
+    pub(self) fn name(&self) -> &'static std::primitive::str {
+        match self {
+            ListingAction::Favourite => "favourite",
+            ListingAction::Hide => "hide",
+            ListingAction::Note => "note",
+        }
+    }
+
+    /// Text for the button.
+    pub(self) fn button(&self) -> &'static std::primitive::str {
+        match self {
+            ListingAction::Favourite => "⭐ Favourite",
+            ListingAction::Hide => "🚫 Hide",
+            ListingAction::Note => "📝 Note",
+        }
+    }
+
+    /// Inline keyboard with every action for the listing.
+    ///
+    /// # Arguments
+    /// * `url` - Listing URL.
+    pub(crate) fn keyboard(url: &std::primitive::str) -> teloxide::types::InlineKeyboardMarkup {
+        let key: std::string::String = super::Listing::key(url);
+        teloxide::types::InlineKeyboardMarkup::new([ListingAction::ALL.map(|action| {
+            teloxide::types::InlineKeyboardButton::callback(
+                action.button(),
+                format!("{}:{}", action.name(), key),
+            )
+        })])
+    }
+
+    /// Parse action and listing key from callback data.
+    ///
+    /// # Arguments
+    /// * `data` - Callback data.
+    pub(super) fn parse(
+        data: &std::primitive::str,
+    ) -> std::option::Option<(Self, std::string::String)> {
+        let (name, key) = data.split_once(':')?;
+        ListingAction::ALL
+            .into_iter()
+            .find(|action| action.name() == name)
+            .map(|action| (action, key.to_string()))
+    }
+}
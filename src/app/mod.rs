@@ -1,17 +1,82 @@
+mod alert;
+mod amenities;
+mod amenity;
 mod announcement;
 mod app;
 mod args;
+mod bot;
+mod bot_command;
+mod bot_state;
+mod decision;
 mod error;
 mod house;
+mod house_notification;
+mod house_settings;
 mod internet;
+mod internet_offer;
+mod last_run;
+mod listing;
+mod listing_action;
+mod monthly_cost;
+mod notification;
+mod notifier;
+mod outcome;
+mod output;
+mod purchase_cost;
+mod quiet_hours;
 mod result;
+mod result_record;
+mod routing;
+mod saved_search;
+mod score;
 
+pub(crate) use self::alert::Alert;
+pub(crate) use self::amenities::Amenities;
+pub(crate) use self::amenities::AmenityKm;
+pub(crate) use self::amenity::Amenity;
 pub(crate) use self::announcement::Announcement;
 pub(crate) use self::error::Error;
+pub(crate) use self::house_notification::HouseNotification;
 pub(crate) use self::internet::Internet;
+pub(crate) use self::internet_offer::InternetOffer;
+pub(crate) use self::listing_action::ListingAction;
+pub(crate) use self::notification::Notification;
+pub(crate) use self::notifier::Notifier;
+pub(crate) use self::routing::Routing;
 
+pub(super) use self::app::notifiers;
 pub(super) use self::app::run;
+pub(super) use self::app::search;
 pub(super) use self::args::Args;
+pub(super) use self::bot::bot;
 
-pub(self) use self::house::House;
-pub(self) use self::result::Result;
+use self::bot_command::BotCommand;
+use self::bot_state::BotState;
+use self::decision::Decision;
+use self::house::House;
+use self::house_settings::HouseSettings;
+use self::last_run::LastRun;
+use self::listing::Listing;
+use self::listing::LISTINGS;
+use self::monthly_cost::MonthlyCost;
+use self::monthly_cost::MonthlyCostSettings;
+use self::monthly_cost::MonthlyCostValues;
+use self::notifier::notify_all;
+use self::outcome::Outcome;
+use self::output::Output;
+use self::purchase_cost::PurchaseCost;
+use self::purchase_cost::PurchaseCostSettings;
+use self::quiet_hours::QuietHours;
+use self::result::Result;
+use self::result_record::InternetOfferRecord;
+use self::result_record::MonthlyCostRecord;
+use self::result_record::NetworkRecord;
+use self::result_record::PurchaseCostRecord;
+use self::result_record::ResultRecord;
+use self::result_record::ScoreRecord;
+use self::result_record::SCHEMA_VERSION;
+use self::saved_search::SavedSearch;
+use self::saved_search::SAVED_SEARCHES;
+use self::score::Score;
+use self::score::ScoreValues;
+use self::score::ScoreWeights;
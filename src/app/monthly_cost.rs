@@ -0,0 +1,179 @@
+/// Settings for estimating monthly cost of ownership.
+#[derive(Debug, Clone)]
+pub(crate) struct MonthlyCostSettings {
+    pub(super) loan_to_value_percent: std::primitive::f64,
+    pub(super) interest_rate_percent: std::primitive::f64,
+    pub(super) loan_term_years: std::primitive::u16,
+    pub(super) property_tax_percents:
+        std::collections::HashMap<std::string::String, std::primitive::f64>,
+    pub(super) property_tax_default_percent: std::primitive::f64,
+    pub(super) commute_euros_per_route_km: std::primitive::f64,
+}
+
+/// House values for estimating monthly cost of ownership.
+pub(crate) struct MonthlyCostValues {
+    /// Price in euros.
+    pub(super) euros: std::primitive::u32,
+    /// Heated area in square meters.
+    pub(super) square_meters: std::option::Option<std::primitive::u16>,
+    /// Municipality for the property tax rate.
+    pub(super) municipality: std::option::Option<std::string::String>,
+    /// Heating system.
+    pub(super) heating: std::option::Option<std::string::String>,
+    /// Energy class.
+    pub(super) energy_class: std::option::Option<std::string::String>,
+    /// Price of the cheapest qualifying internet.
+    pub(super) internet_euros_per_month: std::option::Option<std::primitive::f32>,
+    /// One way commute distance in kilometers, biking route or straight line distance.
+    pub(super) commute_km: std::option::Option<std::primitive::u16>,
+}
+
+/// Estimated monthly cost of ownership in euros.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MonthlyCost {
+    pub(super) mortgage: std::primitive::f64,
+    pub(super) property_tax: std::primitive::f64,
+    pub(super) heating: std::primitive::f64,
+    pub(super) internet: std::primitive::f64,
+    pub(super) commute: std::primitive::f64,
+}
+
+/// Taxable value of the property compared to it's price.
+const TAX_VALUE_RATIO: std::primitive::f64 = 0.7;
+
+/// Working days in a month for commuting.
+const WORKING_DAYS_PER_MONTH: std::primitive::f64 = 21.0;
+
+impl MonthlyCost {
+    /// Estimate monthly cost of ownership.
+    ///
+    /// # Arguments
+    /// * `settings` - Settings for the estimate.
+    /// * `values` - House values for the estimate.
+    pub(super) fn estimate(settings: &MonthlyCostSettings, values: MonthlyCostValues) -> Self {
+        let MonthlyCostValues {
+            euros,
+            square_meters,
+            municipality,
+            heating,
+            energy_class,
+            internet_euros_per_month,
+            commute_km,
+        } = values;
+        Self {
+            mortgage: Self::mortgage(settings, euros as std::primitive::f64),
+            property_tax: euros as std::primitive::f64
+                * TAX_VALUE_RATIO
+                * match municipality {
+                    Some(municipality) => settings
+                        .property_tax_percents
+                        .get(&municipality.to_lowercase())
+                        .copied()
+                        .unwrap_or(settings.property_tax_default_percent),
+                    None => settings.property_tax_default_percent,
+                }
+                / 100.0
+                / 12.0,
+            heating: match square_meters {
+                Some(square_meters) => {
+                    square_meters as std::primitive::f64
+                        * Self::kwh_per_square_meter(energy_class)
+                        * Self::euros_per_kwh(heating)
+                        / 12.0
+                }
+                None => 0.0,
+            },
+            internet: internet_euros_per_month.unwrap_or(0.0) as std::primitive::f64,
+            commute: match commute_km {
+                Some(commute_km) => {
+                    commute_km as std::primitive::f64
+                        * 2.0
+                        * WORKING_DAYS_PER_MONTH
+                        * settings.commute_euros_per_route_km
+                }
+                None => 0.0,
+            },
+        }
+    }
+
+    /// Monthly annuity mortgage payment.
+    ///
+    /// # Arguments
+    /// * `settings` - Settings for the loan.
+    /// * `euros` - Price in euros.
+    pub(self) fn mortgage(
+        settings: &MonthlyCostSettings,
+        euros: std::primitive::f64,
+    ) -> std::primitive::f64 {
+        let loan: std::primitive::f64 = euros * settings.loan_to_value_percent / 100.0;
+        let months: std::primitive::f64 = settings.loan_term_years as std::primitive::f64 * 12.0;
+        if months <= 0.0 {
+            return 0.0;
+        }
+        let rate: std::primitive::f64 = settings.interest_rate_percent / 100.0 / 12.0;
+        if rate <= 0.0 {
+            return loan / months;
+        }
+        loan * rate / (1.0 - (1.0 + rate).powf(-months))
+    }
+
+    /// Yearly heating energy consumption in kilowatt hours per square meter by energy class.
+    ///
+    /// # Arguments
+    /// * `energy_class` - Optional energy class, for example C2018.
+    pub(self) fn kwh_per_square_meter(
+        energy_class: std::option::Option<std::string::String>,
+    ) -> std::primitive::f64 {
+        match energy_class.and_then(|energy_class| energy_class.to_uppercase().chars().next()) {
+            Some('A') => 75.0,
+            Some('B') => 100.0,
+            Some('C') => 130.0,
+            Some('D') => 160.0,
+            Some('E') => 190.0,
+            Some('F') => 240.0,
+            Some('G') => 300.0,
+            _ => 170.0,
+        }
+    }
+
+    /// Price of heat in euros per kilowatt hour by heating system.
+    ///
+    /// # Arguments
+    /// * `heating` - Optional heating system, for example GEOTHERMAL_HEATING.
+    pub(self) fn euros_per_kwh(
+        heating: std::option::Option<std::string::String>,
+    ) -> std::primitive::f64 {
+        let heating: std::string::String = heating.unwrap_or_default().to_uppercase();
+        if heating.contains("GEOTHERMAL") || heating.contains("GROUND") {
+            return 0.05;
+        } else if heating.contains("PUMP") || heating.contains("WOOD") || heating.contains("PELLET")
+        {
+            // Air source heat pump and wood are about as cheap.
+            return 0.07;
+        } else if heating.contains("DISTRICT") {
+            return 0.10;
+        } else if heating.contains("OIL") {
+            return 0.14;
+        }
+        // Direct electric heating.
+        0.15
+    }
+
+    /// Total monthly cost.
+    pub(crate) fn total(&self) -> std::primitive::f64 {
+        self.mortgage + self.property_tax + self.heating + self.internet + self.commute
+    }
+
+    /// Breakdown of the monthly cost.
+    pub(crate) fn breakdown(
+        &self,
+    ) -> std::vec::Vec<(&'static std::primitive::str, std::primitive::f64)> {
+        std::vec![
+            ("Mortgage", self.mortgage),
+            ("Property tax", self.property_tax),
+            ("Heating", self.heating),
+            ("Internet", self.internet),
+            ("Commute", self.commute),
+        ]
+    }
+}
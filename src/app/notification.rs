@@ -0,0 +1,16 @@
+/// Results of a search formatted for notifiers.
+#[derive(Debug, Clone)]
+pub(crate) struct Notification {
+    /// Found houses in sorted order.
+    pub(crate) houses: std::vec::Vec<super::HouseNotification>,
+    /// Price change alerts for favourite houses.
+    pub(crate) alerts: std::vec::Vec<super::Alert>,
+    /// Number of found houses and rejections.
+    pub(crate) summary: std::string::String,
+    /// HTML table of found houses.
+    pub(crate) table_html: std::string::String,
+    /// Path to CSV file of found houses, if any were found.
+    pub(crate) csv_path: std::option::Option<std::string::String>,
+    /// Send houses as digest, even if notifier would send them one by one.
+    pub(crate) digest: std::primitive::bool,
+}
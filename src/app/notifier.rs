@@ -0,0 +1,131 @@
+/// Trait to define notification channel for results.
+pub(crate) trait Notifier: Send + Sync {
+    /// Notify about results of a search.
+    ///
+    /// # Arguments
+    /// * `notification` - Results of the search formatted for notifiers.
+    fn notify<'a>(
+        &'a self,
+        notification: &'a super::Notification,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<Output = std::result::Result<(), super::Error>> + Send + 'a,
+        >,
+    >;
+
+    /// Send plain text message, for example an error.
+    ///
+    /// # Arguments
+    /// * `message` - Message to send.
+    fn send_message<'a>(
+        &'a self,
+        message: &'a std::primitive::str,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<Output = std::result::Result<(), super::Error>> + Send + 'a,
+        >,
+    >;
+}
+
+/// Notify every notifier, also when some of them fail.
+///
+/// # Arguments
+/// * `notifiers` - Notifiers to notify.
+/// * `notification` - Results of the search formatted for notifiers.
+///
+/// # Returns
+/// Errors of the failed notifiers after every notifier was tried.
+pub(super) async fn notify_all(
+    notifiers: &[std::sync::Arc<dyn Notifier>],
+    notification: &super::Notification,
+) -> std::result::Result<(), super::Error> {
+    let mut errors: std::vec::Vec<super::Error> = std::vec::Vec::<super::Error>::new();
+    for notifier in notifiers {
+        if let Err(error) = notifier.notify(notification).await {
+            errors.push(error);
+        }
+    }
+    super::Error::from_notifier_errors(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    /// Notifier that counts notifications and fails, if asked.
+    struct Counter {
+        count: std::sync::atomic::AtomicUsize,
+        fail: std::primitive::bool,
+    }
+
+    impl super::Notifier for Counter {
+        fn notify<'a>(
+            &'a self,
+            _notification: &'a crate::app::Notification,
+        ) -> std::pin::Pin<
+            std::boxed::Box<
+                dyn std::future::Future<Output = std::result::Result<(), crate::app::Error>>
+                    + Send
+                    + 'a,
+            >,
+        > {
+            std::boxed::Box::pin(async move {
+                let _: std::primitive::usize =
+                    self.count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                if self.fail {
+                    return Err(crate::app::Error::LocationNotFound("test".to_string()));
+                }
+                Ok(())
+            })
+        }
+
+        fn send_message<'a>(
+            &'a self,
+            _message: &'a std::primitive::str,
+        ) -> std::pin::Pin<
+            std::boxed::Box<
+                dyn std::future::Future<Output = std::result::Result<(), crate::app::Error>>
+                    + Send
+                    + 'a,
+            >,
+        > {
+            std::boxed::Box::pin(async move { Ok(()) })
+        }
+    }
+
+    fn counter(fail: std::primitive::bool) -> std::sync::Arc<Counter> {
+        std::sync::Arc::new(Counter {
+            count: std::sync::atomic::AtomicUsize::new(0),
+            fail,
+        })
+    }
+
+    #[tokio::test]
+    async fn notifies_every_notifier_after_failures() {
+        let counters: std::vec::Vec<std::sync::Arc<Counter>> =
+            std::vec![counter(true), counter(false), counter(true)];
+        let notifiers: std::vec::Vec<std::sync::Arc<dyn super::Notifier>> = counters
+            .iter()
+            .map(|counter| -> std::sync::Arc<dyn super::Notifier> {
+                std::sync::Arc::<Counter>::clone(counter)
+            })
+            .collect();
+        let result: std::result::Result<(), crate::app::Error> = super::notify_all(
+            &notifiers,
+            &crate::app::Notification {
+                houses: std::vec::Vec::<crate::app::HouseNotification>::new(),
+                alerts: std::vec::Vec::<crate::app::Alert>::new(),
+                summary: std::string::String::new(),
+                table_html: std::string::String::new(),
+                csv_path: None,
+                digest: false,
+            },
+        )
+        .await;
+        for counter in &counters {
+            assert_eq!(counter.count.load(std::sync::atomic::Ordering::SeqCst), 1);
+        }
+        assert!(matches!(
+            result,
+            Err(crate::app::Error::NotifierErrors(errors)) if errors.len() == 2
+        ));
+    }
+}
@@ -0,0 +1,50 @@
+/// Outcome for a fetched announcement.
+pub(crate) struct Outcome {
+    pub(super) url: std::string::String,
+    pub(super) euros: std::option::Option<std::primitive::u32>,
+    pub(super) decision: super::Decision,
+    pub(super) result: std::option::Option<super::Result>,
+}
+
+impl Outcome {
+    /// Write report of every outcome to CSV file.
+    ///
+    /// # Arguments
+    /// * `outcomes` - Outcomes.
+    ///
+    /// # Returns
+    /// Path to the CSV file.
+    pub(super) fn write_report(
+        outcomes: &std::vec::Vec<Self>,
+    ) -> std::result::Result<std::string::String, std::io::Error> {
+        let path: std::string::String = super::Result::output_path("explain", "csv")?;
+        let mut writer: csv::Writer<std::fs::File> =
+            csv::Writer::from_writer(super::Result::output_file(&path)?);
+        writer.write_record(["URL", "Outcome", "Filter", "Value", "Threshold"])?;
+        for outcome in outcomes {
+            writer.write_record(match &outcome.decision {
+                super::Decision::Include => [
+                    outcome.url.clone(),
+                    "included".to_string(),
+                    "".to_string(),
+                    "".to_string(),
+                    "".to_string(),
+                ],
+                super::Decision::Reject {
+                    filter,
+                    value,
+                    threshold,
+                } => [
+                    outcome.url.clone(),
+                    "rejected".to_string(),
+                    filter.to_string(),
+                    value.clone(),
+                    threshold.clone(),
+                ],
+            })?;
+        }
+        writer.flush()?;
+
+        Ok(path)
+    }
+}
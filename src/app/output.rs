@@ -0,0 +1,326 @@
+/// Output of the results in CSV, JSON or JSON Lines format.
+/// Opened once for every search, so that results of every saved search end up in the same output.
+pub(crate) struct Output {
+    /// Output format, one of: CSV, JSON or JSONL
+    pub(self) format: std::string::String,
+    /// Path to the file, `-` for stdout. New file in the same directory as executable if not given.
+    pub(self) path: std::option::Option<std::string::String>,
+    /// Writer and path of the opened file, None for stdout.
+    pub(self) writer: std::option::Option<(
+        std::boxed::Box<dyn std::io::Write + Send>,
+        std::option::Option<std::string::String>,
+    )>,
+    /// Number of written results.
+    pub(self) count: std::primitive::usize,
+}
+
+impl Output {
+    /// Create new output, that is opened when the first results are written.
+    ///
+    /// # Arguments
+    /// * `format` - Output format, one of: CSV, JSON or JSONL
+    /// * `path` - Optional path to the file, `-` for stdout. New file in the same directory as executable if not given.
+    pub(super) fn new(
+        format: &std::primitive::str,
+        path: std::option::Option<&std::primitive::str>,
+    ) -> Self {
+        Self {
+            format: format.to_string(),
+            path: path.map(|path| path.to_string()),
+            writer: None,
+            count: 0,
+        }
+    }
+
+    /// Open the output, if not yet opened.
+    pub(self) fn open(&mut self) -> std::result::Result<(), std::io::Error> {
+        if self.writer.is_some() {
+            return Ok(());
+        }
+        let (writer, path): (
+            std::boxed::Box<dyn std::io::Write + Send>,
+            std::option::Option<std::string::String>,
+        ) = match self.path.as_deref() {
+            Some("-") => (std::boxed::Box::new(std::io::stdout()), None),
+            Some(path) => (
+                std::boxed::Box::new(std::fs::File::create(path)?),
+                Some(path.to_string()),
+            ),
+            None => {
+                let path: std::string::String = super::Result::output_path(
+                    "results",
+                    match self.format.as_str() {
+                        "JSON" => "json",
+                        "JSONL" => "jsonl",
+                        _ => "csv",
+                    },
+                )?;
+                (
+                    std::boxed::Box::new(super::Result::output_file(&path)?),
+                    Some(path),
+                )
+            }
+        };
+        self.open_writer(writer, path)
+    }
+
+    /// Start output to the writer.
+    ///
+    /// # Arguments
+    /// * `writer` - Writer to write to.
+    /// * `path` - Path of the file, None for stdout.
+    pub(self) fn open_writer(
+        &mut self,
+        mut writer: std::boxed::Box<dyn std::io::Write + Send>,
+        path: std::option::Option<std::string::String>,
+    ) -> std::result::Result<(), std::io::Error> {
+        match self.format.as_str() {
+            "JSON" => writer.write_all(b"[")?,
+            "JSONL" => {}
+            _ => super::Result::write_csv_to(&[], &mut writer, true)?,
+        }
+        self.writer = Some((writer, path));
+        Ok(())
+    }
+
+    /// Write results to the output.
+    /// Results are written if found or if the output was asked for, so that empty output can be piped.
+    ///
+    /// # Arguments
+    /// * `results` - Results.
+    pub(super) fn write(
+        &mut self,
+        results: &[super::Result],
+    ) -> std::result::Result<(), std::io::Error> {
+        if results.is_empty() && self.path.is_none() {
+            return Ok(());
+        }
+        self.open()?;
+        let writer: &mut std::boxed::Box<dyn std::io::Write + Send> = match &mut self.writer {
+            Some((writer, _)) => writer,
+            None => return Ok(()),
+        };
+        match self.format.as_str() {
+            "JSON" => {
+                for result in results {
+                    // Records are separated with comma in the array.
+                    writer.write_all(if self.count == 0 { b"\n" } else { b",\n" })?;
+                    serde_json::to_writer_pretty(&mut *writer, &result.record())?;
+                    self.count += 1;
+                }
+            }
+            "JSONL" => {
+                for result in results {
+                    serde_json::to_writer(&mut *writer, &result.record())?;
+                    writer.write_all(b"\n")?;
+                    self.count += 1;
+                }
+            }
+            _ => {
+                super::Result::write_csv_to(results, &mut *writer, false)?;
+                self.count += results.len();
+            }
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Finish the output, opening it first if the output was asked for.
+    ///
+    /// # Returns
+    /// Path to the file, None if nothing was written or written to stdout.
+    pub(super) fn finish(
+        mut self,
+    ) -> std::result::Result<std::option::Option<std::string::String>, std::io::Error> {
+        if self.path.is_some() {
+            self.open()?;
+        }
+        let (mut writer, path): (
+            std::boxed::Box<dyn std::io::Write + Send>,
+            std::option::Option<std::string::String>,
+        ) = match self.writer.take() {
+            Some(opened) => opened,
+            None => return Ok(None),
+        };
+        if self.format == "JSON" {
+            writer.write_all(if self.count == 0 { b"]\n" } else { b"\n]\n" })?;
+        }
+        writer.flush()?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    /// Writer to shared buffer, so that the output can be read after writing.
+    #[derive(Clone, Default)]
+    struct Buffer(std::sync::Arc<std::sync::Mutex<std::vec::Vec<std::primitive::u8>>>);
+
+    impl std::io::Write for Buffer {
+        fn write(
+            &mut self,
+            buf: &[std::primitive::u8],
+        ) -> std::result::Result<std::primitive::usize, std::io::Error> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::result::Result<(), std::io::Error> {
+            Ok(())
+        }
+    }
+
+    /// Result with the URL and unknown values.
+    ///
+    /// # Arguments
+    /// * `url` - URL of the result.
+    fn result(url: &std::primitive::str) -> crate::app::Result {
+        crate::app::Result {
+            url: url.to_string(),
+            euros: Some(200000),
+            floors: None,
+            square_meters_house: None,
+            euros_per_square_meter_house: None,
+            square_meters_total: None,
+            euros_per_square_meter_total: None,
+            km_to_location_straight: None,
+            km_to_location_biking: None,
+            minutes_to_location_transit: None,
+            year: None,
+            km_to_amenities: std::collections::HashMap::new(),
+            best_mbps: None,
+            cheapest_internet_euros_per_month: None,
+            internet_offers: None,
+            mobile_network: None,
+            monthly_cost: None,
+            purchase_cost: None,
+            score: crate::app::Score {
+                price_per_square_meter: 0.0,
+                commute: 0.0,
+                year: 0.0,
+                total_area: 0.0,
+                internet: 0.0,
+                energy_class: 0.0,
+                keywords: 0.0,
+            },
+            address: "Katu 1, Tampere".to_string(),
+            photo_url: None,
+            location: None,
+        }
+    }
+
+    /// Write groups of results to output in the format.
+    ///
+    /// # Arguments
+    /// * `format` - Output format.
+    /// * `groups` - Results of every group.
+    fn write(
+        format: &std::primitive::str,
+        groups: &[std::vec::Vec<crate::app::Result>],
+    ) -> std::string::String {
+        let buffer: Buffer = Buffer::default();
+        let mut output: super::Output = super::Output::new(format, Some("-"));
+        output
+            .open_writer(std::boxed::Box::new(buffer.clone()), None)
+            .unwrap();
+        for results in groups {
+            output.write(results).unwrap();
+        }
+        assert_eq!(output.finish().unwrap(), None);
+        return std::string::String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+    }
+
+    #[test]
+    fn writes_every_group_to_one_json_array() {
+        let json: serde_json::Value = serde_json::from_str(&write(
+            "JSON",
+            &[
+                std::vec![
+                    result("https://example.com/1"),
+                    result("https://example.com/2")
+                ],
+                std::vec::Vec::<crate::app::Result>::new(),
+                std::vec![result("https://example.com/3")],
+            ],
+        ))
+        .unwrap();
+        let records: &std::vec::Vec<serde_json::Value> = json.as_array().unwrap();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[2]["url"], "https://example.com/3");
+        assert_eq!(records[0]["schemaVersion"], crate::app::SCHEMA_VERSION);
+        assert_eq!(records[0]["euros"], 200000);
+        assert_eq!(records[0]["internetOffers"], serde_json::Value::Null);
+        assert_eq!(records[0]["score"]["breakdown"]["totalArea"], 0.0);
+    }
+
+    #[test]
+    fn writes_empty_json_array() {
+        let json: serde_json::Value = serde_json::from_str(&write("JSON", &[])).unwrap();
+        assert_eq!(json, serde_json::json!([]));
+    }
+
+    #[test]
+    fn writes_json_lines() {
+        let jsonl: std::string::String = write(
+            "JSONL",
+            &[
+                std::vec![result("https://example.com/1")],
+                std::vec![result("https://example.com/2")],
+            ],
+        );
+        let lines: std::vec::Vec<&std::primitive::str> = jsonl.lines().collect();
+        assert_eq!(lines.len(), 2);
+        for (line, url) in lines
+            .iter()
+            .zip(["https://example.com/1", "https://example.com/2"])
+        {
+            let record: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(record["url"], url);
+        }
+    }
+
+    #[test]
+    fn writes_csv_title_row_once_and_offers_in_one_line() {
+        let mut with_offers: crate::app::Result = result("https://example.com/2");
+        with_offers.internet_offers = Some(
+            [100, 1000]
+                .into_iter()
+                .map(|mbps_down| crate::app::InternetOffer {
+                    provider: "Operaattori".to_string(),
+                    name: format!("Kuitu {}", mbps_down),
+                    technology: "Kuitu".to_string(),
+                    mbps_down,
+                    mbps_up: None,
+                    euros_per_month: 30.0,
+                    delivery_date: "1.1.2026".to_string(),
+                })
+                .collect(),
+        );
+        let csv: std::string::String = write(
+            "CSV",
+            &[
+                std::vec![result("https://example.com/1")],
+                std::vec![with_offers],
+            ],
+        );
+        let mut reader: csv::Reader<&[std::primitive::u8]> =
+            csv::Reader::from_reader(csv.as_bytes());
+        assert!(reader.headers().unwrap().iter().any(|title| title == "URL"));
+        let records: std::vec::Vec<csv::StringRecord> =
+            reader.records().map(|record| record.unwrap()).collect();
+        assert_eq!(records.len(), 2);
+        assert!(records[1].iter().any(|cell| cell
+            == "Operaattori: Kuitu 100 (Kuitu, 1.1.2026-): 30.00 €/kk, 100 Mbit/s; \
+                Operaattori: Kuitu 1000 (Kuitu, 1.1.2026-): 30.00 €/kk, 1000 Mbit/s"));
+    }
+
+    #[test]
+    fn notification_json_is_same_record_as_json_output() {
+        let json: serde_json::Value = serde_json::from_str(&write(
+            "JSON",
+            &[std::vec![result("https://example.com/1")]],
+        ))
+        .unwrap();
+        assert_eq!(result("https://example.com/1").json(), json[0]);
+    }
+}
@@ -0,0 +1,54 @@
+/// Settings for estimating one-off purchase costs.
+#[derive(Debug, Clone)]
+pub(crate) struct PurchaseCostSettings {
+    pub(super) moving_renovation_euros: std::primitive::u32,
+}
+
+/// Estimated one-off purchase costs in euros.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PurchaseCost {
+    pub(super) transfer_tax: std::primitive::f64,
+    pub(super) registration_fees: std::primitive::f64,
+    pub(super) moving_renovation: std::primitive::f64,
+}
+
+/// Transfer tax (varainsiirtovero) rate for properties.
+/// First-time buyer exemption was abolished for purchases from 2024.
+const TRANSFER_TAX_PERCENT: std::primitive::f64 = 3.0;
+
+/// National Land Survey fee for title registration (lainhuuto).
+const TITLE_REGISTRATION_EUROS: std::primitive::f64 = 160.0;
+
+/// National Land Survey fee for electronic mortgage deed (kiinnitys).
+const MORTGAGE_DEED_EUROS: std::primitive::f64 = 40.0;
+
+impl PurchaseCost {
+    /// Estimate one-off purchase costs.
+    ///
+    /// # Arguments
+    /// * `settings` - Settings for the estimate.
+    /// * `euros` - Price in euros.
+    pub(super) fn estimate(settings: &PurchaseCostSettings, euros: std::primitive::u32) -> Self {
+        Self {
+            transfer_tax: euros as std::primitive::f64 * TRANSFER_TAX_PERCENT / 100.0,
+            registration_fees: TITLE_REGISTRATION_EUROS + MORTGAGE_DEED_EUROS,
+            moving_renovation: settings.moving_renovation_euros as std::primitive::f64,
+        }
+    }
+
+    /// Total one-off purchase costs.
+    pub(crate) fn total(&self) -> std::primitive::f64 {
+        self.transfer_tax + self.registration_fees + self.moving_renovation
+    }
+
+    /// Breakdown of the one-off purchase costs.
+    pub(crate) fn breakdown(
+        &self,
+    ) -> std::vec::Vec<(&'static std::primitive::str, std::primitive::f64)> {
+        std::vec![
+            ("Transfer tax", self.transfer_tax),
+            ("Registration fees", self.registration_fees),
+            ("Moving and renovation", self.moving_renovation),
+        ]
+    }
+}
@@ -0,0 +1,202 @@
+/// Notifier that queues notifications during quiet hours
+/// and sends them as digest with the first notification after quiet hours.
+/// Urgent houses and alerts are sent immediately even during quiet hours.
+pub(crate) struct QuietHours {
+    pub(self) start: chrono::NaiveTime,
+    pub(self) end: chrono::NaiveTime,
+    /// Key for the queue of the notifiers.
+    pub(self) key: std::string::String,
+    pub(self) notifiers: std::vec::Vec<std::sync::Arc<dyn super::Notifier>>,
+}
+
+/// Notifications queued during quiet hours.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct Queue {
+    pub(self) houses: std::vec::Vec<super::HouseNotification>,
+    pub(self) alerts: std::vec::Vec<super::Alert>,
+}
+
+/// Name of the store for queued notifications.
+const QUIET_HOURS: &std::primitive::str = "quiet_hours";
+
+impl QuietHours {
+    /// Create new quiet hours notifier.
+    ///
+    /// # Arguments
+    /// * `start` - Start time of quiet hours.
+    /// * `end` - End time of quiet hours, can be on the next day.
+    /// * `key` - Key for the queue of the notifiers.
+    /// * `notifiers` - Notifiers to send notifications to.
+    pub(super) fn new(
+        start: chrono::NaiveTime,
+        end: chrono::NaiveTime,
+        key: std::string::String,
+        notifiers: std::vec::Vec<std::sync::Arc<dyn super::Notifier>>,
+    ) -> Self {
+        Self {
+            start,
+            end,
+            key,
+            notifiers,
+        }
+    }
+
+    /// Is the time during quiet hours?
+    ///
+    /// # Arguments
+    /// * `time` - Time to check.
+    pub(self) fn is_quiet(&self, time: chrono::NaiveTime) -> std::primitive::bool {
+        if self.start <= self.end {
+            return self.start <= time && time < self.end;
+        }
+        // Quiet hours over midnight.
+        self.start <= time || time < self.end
+    }
+
+    /// Send notification to every notifier, also when some of them fail.
+    ///
+    /// # Arguments
+    /// * `notification` - Notification to send.
+    pub(self) async fn send(
+        &self,
+        notification: &super::Notification,
+    ) -> std::result::Result<(), super::Error> {
+        return super::notify_all(&self.notifiers, notification).await;
+    }
+
+    /// Queue notification, sending only the urgent houses and alerts.
+    ///
+    /// # Arguments
+    /// * `notification` - Notification to queue.
+    pub(self) async fn queue(
+        &self,
+        notification: &super::Notification,
+    ) -> std::result::Result<(), super::Error> {
+        let store: crate::store::Store = crate::store::Store::new(QUIET_HOURS)?;
+        let mut queues: std::collections::HashMap<std::string::String, Queue> = store.load()?;
+        let queue: &mut Queue = queues.entry(self.key.clone()).or_default();
+        let mut urgent: super::Notification = super::Notification {
+            houses: std::vec::Vec::<super::HouseNotification>::new(),
+            alerts: std::vec::Vec::<super::Alert>::new(),
+            summary: std::string::String::new(),
+            table_html: std::string::String::new(),
+            csv_path: notification.csv_path.clone(),
+            digest: false,
+        };
+        for house in &notification.houses {
+            // Newer version of the house replaces the queued one.
+            queue.houses.retain(|queued| queued.url != house.url);
+            if house.urgent {
+                urgent.houses.push(house.clone());
+            } else {
+                queue.houses.push(house.clone());
+            }
+        }
+        for alert in &notification.alerts {
+            if alert.urgent {
+                urgent.alerts.push(alert.clone());
+            } else {
+                queue.alerts.push(alert.clone());
+            }
+        }
+        let queued: std::string::String = format!(
+            "Quiet hours until {}, queued {} houses and {} alerts.",
+            self.end.format("%H:%M"),
+            queue.houses.len(),
+            queue.alerts.len()
+        );
+        eprintln!("{}", queued);
+        store.save(&queues)?;
+        if !urgent.houses.is_empty() || !urgent.alerts.is_empty() {
+            urgent.summary = format!("Urgent during quiet hours!\n{}", queued);
+            urgent.table_html = super::Result::table_html(&urgent.houses);
+            self.send(&urgent).await?;
+        }
+        Ok(())
+    }
+
+    /// Send queued notifications as digest, if any.
+    ///
+    /// # Arguments
+    /// * `notification` - Notification that is sent after the digest, houses in it are not repeated.
+    pub(self) async fn flush(
+        &self,
+        notification: &super::Notification,
+    ) -> std::result::Result<(), super::Error> {
+        let store: crate::store::Store = crate::store::Store::new(QUIET_HOURS)?;
+        let mut queues: std::collections::HashMap<std::string::String, Queue> = store.load()?;
+        let mut queue: Queue = match queues.remove(&self.key) {
+            Some(queue) => queue,
+            None => return Ok(()),
+        };
+        queue.houses.retain(|queued| {
+            !notification
+                .houses
+                .iter()
+                .any(|house| house.url == queued.url)
+        });
+        if !queue.houses.is_empty() || !queue.alerts.is_empty() {
+            self.send(&super::Notification {
+                table_html: super::Result::table_html(&queue.houses),
+                summary: format!(
+                    "Queued during quiet hours: {} houses and {} alerts.",
+                    queue.houses.len(),
+                    queue.alerts.len()
+                ),
+                houses: queue.houses,
+                alerts: queue.alerts,
+                csv_path: None,
+                digest: true,
+            })
+            .await?;
+        }
+        // Removed from the queue only after sending, so that failed digest is tried again.
+        store.save(&queues)?;
+        Ok(())
+    }
+}
+
+impl super::Notifier for QuietHours {
+    /// Queue notification during quiet hours, else send queued digest and the notification.
+    fn notify<'a>(
+        &'a self,
+        notification: &'a super::Notification,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<Output = std::result::Result<(), super::Error>> + Send + 'a,
+        >,
+    > {
+        std::boxed::Box::pin(async move {
+            if self.is_quiet(chrono::Local::now().time()) {
+                return self.queue(notification).await;
+            }
+            // Notification is sent even if the digest failed.
+            let flushed: std::result::Result<(), super::Error> = self.flush(notification).await;
+            let sent: std::result::Result<(), super::Error> = self.send(notification).await;
+            super::Error::from_notifier_errors(
+                flushed.err().into_iter().chain(sent.err()).collect(),
+            )
+        })
+    }
+
+    /// Send message immediately to every notifier, also during quiet hours.
+    fn send_message<'a>(
+        &'a self,
+        message: &'a std::primitive::str,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<Output = std::result::Result<(), super::Error>> + Send + 'a,
+        >,
+    > {
+        std::boxed::Box::pin(async move {
+            let mut errors: std::vec::Vec<super::Error> = std::vec::Vec::<super::Error>::new();
+            for notifier in &self.notifiers {
+                if let Err(error) = notifier.send_message(message).await {
+                    errors.push(error);
+                }
+            }
+            super::Error::from_notifier_errors(errors)
+        })
+    }
+}
@@ -1,16 +1,49 @@
 /// Store formatted result.
-pub(super) struct Result {
-    pub(self) url: std::string::String,
-    pub(self) thousands_of_euros: std::option::Option<std::primitive::u32>,
-    pub(self) floors: std::option::Option<std::primitive::u8>,
-    pub(self) square_meters_house: std::option::Option<std::primitive::u16>,
-    pub(self) euros_per_square_meter_house: std::option::Option<std::primitive::u32>,
-    pub(self) square_meters_total: std::option::Option<std::primitive::u16>,
-    pub(self) euros_per_square_meter_total: std::option::Option<std::primitive::u32>,
-    pub(self) km_to_location_straight: std::option::Option<std::primitive::u16>,
-    pub(self) km_to_location_biking: std::option::Option<std::primitive::u16>,
-    pub(self) year: std::option::Option<std::primitive::u16>,
-    pub(self) internet_strings: std::vec::Vec<std::string::String>,
+pub(crate) struct Result {
+    /// URL.
+    pub(super) url: std::string::String,
+    /// Price in euros.
+    pub(super) euros: std::option::Option<std::primitive::u32>,
+    /// Number of floors.
+    pub(super) floors: std::option::Option<std::primitive::u8>,
+    /// Square meters for the house.
+    pub(super) square_meters_house: std::option::Option<std::primitive::u16>,
+    /// Price per square meter for the house.
+    pub(super) euros_per_square_meter_house: std::option::Option<std::primitive::u32>,
+    /// Total square meters.
+    pub(super) square_meters_total: std::option::Option<std::primitive::u16>,
+    /// Price per square meter for the total.
+    pub(super) euros_per_square_meter_total: std::option::Option<std::primitive::u32>,
+    /// Distance to location straight.
+    pub(super) km_to_location_straight: std::option::Option<std::primitive::u16>,
+    /// Distance to location biking.
+    pub(super) km_to_location_biking: std::option::Option<std::primitive::u16>,
+    /// Public transport journey time to location.
+    pub(super) minutes_to_location_transit: std::option::Option<std::primitive::u16>,
+    /// Construction year.
+    pub(super) year: std::option::Option<std::primitive::u16>,
+    /// Distances to nearest amenities.
+    pub(super) km_to_amenities: crate::app::AmenityKm,
+    /// Best available internet speed in megabits per second.
+    pub(super) best_mbps: std::option::Option<std::primitive::u32>,
+    /// Price for the cheapest fast enough internet.
+    pub(super) cheapest_internet_euros_per_month: std::option::Option<std::primitive::f32>,
+    /// Internet offers, None if internet availability is unknown.
+    pub(super) internet_offers: std::option::Option<std::vec::Vec<super::InternetOffer>>,
+    /// Best mobile network.
+    pub(super) mobile_network: std::option::Option<crate::coverage::Network>,
+    /// Estimated monthly cost of ownership.
+    pub(super) monthly_cost: std::option::Option<super::MonthlyCost>,
+    /// Estimated one-off purchase costs.
+    pub(super) purchase_cost: std::option::Option<super::PurchaseCost>,
+    /// Weighted score.
+    pub(super) score: super::Score,
+    /// Street address with municipality.
+    pub(super) address: std::string::String,
+    /// URL for the main photo.
+    pub(super) photo_url: std::option::Option<std::string::String>,
+    /// Location of the house.
+    pub(super) location: std::option::Option<longitude::Location>,
 }
 
 /// Information about a field.
@@ -30,8 +63,29 @@ pub(self) struct FieldToInfo {
     pub(self) euros_per_square_meter_total: FieldInfo,
     pub(self) km_to_location_straight: FieldInfo,
     pub(self) km_to_location_biking: FieldInfo,
+    pub(self) minutes_to_location_transit: FieldInfo,
     pub(self) year: FieldInfo,
-    pub(self) internet_strings: FieldInfo,
+    pub(self) best_mbps: FieldInfo,
+    pub(self) cheapest_internet_euros_per_month: FieldInfo,
+    pub(self) internet_offers: FieldInfo,
+    pub(self) mobile_network: FieldInfo,
+    pub(self) monthly_cost: FieldInfo,
+    pub(self) purchase_cost: FieldInfo,
+    pub(self) score: FieldInfo,
+}
+
+/// Line of the message after the URL.
+enum MessageLine {
+    /// Field with it's title and value with unit.
+    Field {
+        title: &'static std::primitive::str,
+        value: std::string::String,
+    },
+    /// Item of the previous field, for example part of a cost breakdown.
+    Item {
+        title: std::option::Option<&'static std::primitive::str>,
+        value: std::string::String,
+    },
 }
 
 /// Field to information map.
@@ -72,155 +126,364 @@ const FIELD_TO_INFO: FieldToInfo = FieldToInfo {
         title: "Biking to location",
         unit: Some("km"),
     },
+    minutes_to_location_transit: FieldInfo {
+        title: "Public transport to location",
+        unit: Some("min"),
+    },
     year: FieldInfo {
         title: "Year",
         unit: None,
     },
-    internet_strings: FieldInfo {
+    best_mbps: FieldInfo {
+        title: "Best internet",
+        unit: Some("Mbit/s"),
+    },
+    cheapest_internet_euros_per_month: FieldInfo {
+        title: "Cheapest internet",
+        unit: Some("€/kk"),
+    },
+    internet_offers: FieldInfo {
         title: "Internet",
         unit: None,
     },
+    mobile_network: FieldInfo {
+        title: "Mobile",
+        unit: None,
+    },
+    monthly_cost: FieldInfo {
+        title: "Monthly cost",
+        unit: Some("€/kk"),
+    },
+    purchase_cost: FieldInfo {
+        title: "One-off costs",
+        unit: Some("€"),
+    },
+    score: FieldInfo {
+        title: "Score",
+        unit: None,
+    },
 };
 
 impl Result {
-    /// Create a new result.
-    ///
-    /// # Arguments
-    /// * `url` - URL.
-    /// * `thousands_of_euros` - Optional price in thousands of euros.
-    /// * `floors` - Optional number of floors.
-    /// * `square_meters_house` - Optional square meters for the house.
-    /// * `euros_per_square_meter_house` - Optional price per square meter for the house.
-    /// * `square_meters_total` - Optional total square meters.
-    /// * `euros_per_square_meter_total` - Optional price per square meter for the total.
-    /// * `km_to_location_straight` - Optional distance to location straight.
-    /// * `km_to_location_biking` - Optional distance to location biking.
-    /// * `year` - Optional construction year.
-    /// * `internet_strings` - Internet products as strings.
-    pub(super) fn new(
-        url: std::string::String,
-        thousands_of_euros: std::option::Option<std::primitive::u32>,
-        floors: std::option::Option<std::primitive::u8>,
-        square_meters_house: std::option::Option<std::primitive::u16>,
-        euros_per_square_meter_house: std::option::Option<std::primitive::u32>,
-        square_meters_total: std::option::Option<std::primitive::u16>,
-        euros_per_square_meter_total: std::option::Option<std::primitive::u32>,
-        km_to_location_straight: std::option::Option<std::primitive::u16>,
-        km_to_location_biking: std::option::Option<std::primitive::u16>,
-        year: std::option::Option<std::primitive::u16>,
-        internet_strings: std::vec::Vec<std::string::String>,
-    ) -> Self {
-        Self {
-            url,
-            thousands_of_euros,
-            square_meters_house,
-            euros_per_square_meter_house,
-            square_meters_total,
-            euros_per_square_meter_total,
-            km_to_location_straight,
-            km_to_location_biking,
-            year,
-            internet_strings,
-            floors,
-        }
-    }
-
     /// Generate message line.
     ///
     /// # Arguments
     /// * `info` - Field information.
     /// * `value` - Field value.
-    pub(self) fn message_line(info: FieldInfo, value: std::string::String) -> std::string::String {
-        format!(
-            "\n\t{}: {}{}",
-            info.title,
-            value,
-            match info.unit {
-                Some(unit) => format!(" {}", unit),
-                None => "".to_string(),
-            }
-        )
+    pub(self) fn message_line(info: FieldInfo, value: std::string::String) -> MessageLine {
+        MessageLine::Field {
+            title: info.title,
+            value: match info.unit {
+                Some(unit) => format!("{} {}", value, unit),
+                None => value,
+            },
+        }
     }
 
-    /// Generate message.
-    pub(super) fn message(&self) -> std::string::String {
-        let mut message: std::string::String = std::string::String::new();
-        message.push_str(&self.url);
-        message.push_str(":");
+    /// URL of the listing.
+    pub(super) fn url(&self) -> &std::primitive::str {
+        &self.url
+    }
+
+    /// Price in thousands of euros, as shown in messages and CSV.
+    pub(self) fn thousands_of_euros(&self) -> std::option::Option<std::primitive::u32> {
+        self.euros.map(|euros| euros / 1000)
+    }
 
-        if let Some(thousands_of_euros) = self.thousands_of_euros {
-            message.push_str(&Self::message_line(
+    /// Generate message lines after the URL.
+    pub(self) fn message_lines(&self) -> std::vec::Vec<MessageLine> {
+        let mut lines: std::vec::Vec<MessageLine> = std::vec::Vec::<MessageLine>::new();
+
+        if let Some(thousands_of_euros) = self.thousands_of_euros() {
+            lines.push(Self::message_line(
                 FIELD_TO_INFO.thousands_of_euros,
                 thousands_of_euros.to_string(),
             ));
         }
 
         if let Some(floors) = self.floors {
-            message.push_str(&Self::message_line(
-                FIELD_TO_INFO.floors,
-                floors.to_string(),
-            ));
+            lines.push(Self::message_line(FIELD_TO_INFO.floors, floors.to_string()));
         }
 
         if let Some(square_meters_house) = self.square_meters_house {
-            message.push_str(&Self::message_line(
+            lines.push(Self::message_line(
                 FIELD_TO_INFO.square_meters_house,
                 square_meters_house.to_string(),
             ));
         }
 
         if let Some(euros_per_square_meter_house) = self.euros_per_square_meter_house {
-            message.push_str(&Self::message_line(
+            lines.push(Self::message_line(
                 FIELD_TO_INFO.euros_per_square_meter_house,
                 euros_per_square_meter_house.to_string(),
             ));
         }
 
         if let Some(square_meters_total) = self.square_meters_total {
-            message.push_str(&Self::message_line(
+            lines.push(Self::message_line(
                 FIELD_TO_INFO.square_meters_total,
                 square_meters_total.to_string(),
             ));
         }
 
         if let Some(euros_per_square_meter_total) = self.euros_per_square_meter_total {
-            message.push_str(&Self::message_line(
+            lines.push(Self::message_line(
                 FIELD_TO_INFO.euros_per_square_meter_total,
                 euros_per_square_meter_total.to_string(),
             ));
         }
 
         if let Some(km_to_location_straight) = self.km_to_location_straight {
-            message.push_str(&Self::message_line(
+            lines.push(Self::message_line(
                 FIELD_TO_INFO.km_to_location_straight,
                 km_to_location_straight.to_string(),
             ));
         }
 
         if let Some(km_to_location_biking) = self.km_to_location_biking {
-            message.push_str(&Self::message_line(
+            lines.push(Self::message_line(
                 FIELD_TO_INFO.km_to_location_biking,
                 km_to_location_biking.to_string(),
             ));
         }
 
+        if let Some(minutes_to_location_transit) = self.minutes_to_location_transit {
+            lines.push(Self::message_line(
+                FIELD_TO_INFO.minutes_to_location_transit,
+                minutes_to_location_transit.to_string(),
+            ));
+        }
+
         if let Some(year) = self.year {
-            message.push_str(&Self::message_line(FIELD_TO_INFO.year, year.to_string()));
+            lines.push(Self::message_line(FIELD_TO_INFO.year, year.to_string()));
         }
 
-        if !self.internet_strings.is_empty() {
-            message.push_str(&Self::message_line(
-                FIELD_TO_INFO.internet_strings,
-                self.internet_strings
-                    .iter()
-                    .map(|internet_string| format!("\n\t- {}", internet_string))
-                    .collect::<std::string::String>(),
+        for amenity in crate::app::Amenity::ALL {
+            if let Some(km_to_amenity) = self.km_to_amenities.get(&amenity) {
+                lines.push(Self::message_line(
+                    Self::amenity_info(amenity),
+                    format!("{:.1}", km_to_amenity),
+                ));
+            }
+        }
+
+        if let Some(best_mbps) = self.best_mbps {
+            lines.push(Self::message_line(
+                FIELD_TO_INFO.best_mbps,
+                best_mbps.to_string(),
             ));
         }
 
+        if let Some(cheapest_internet_euros_per_month) = self.cheapest_internet_euros_per_month {
+            lines.push(Self::message_line(
+                FIELD_TO_INFO.cheapest_internet_euros_per_month,
+                format!("{:.2}", cheapest_internet_euros_per_month),
+            ));
+        }
+
+        match &self.internet_offers {
+            Some(internet_offers) => {
+                if !internet_offers.is_empty() {
+                    lines.push(Self::message_line(
+                        FIELD_TO_INFO.internet_offers,
+                        "".to_string(),
+                    ));
+                    for internet_offer in internet_offers {
+                        lines.push(MessageLine::Item {
+                            title: None,
+                            value: internet_offer.to_str(),
+                        });
+                    }
+                }
+            }
+            None => {
+                lines.push(Self::message_line(
+                    FIELD_TO_INFO.internet_offers,
+                    "unknown".to_string(),
+                ));
+            }
+        }
+
+        if let Some(mobile_network) = &self.mobile_network {
+            lines.push(Self::message_line(
+                FIELD_TO_INFO.mobile_network,
+                mobile_network.to_str(),
+            ));
+        }
+
+        if let Some(monthly_cost) = &self.monthly_cost {
+            lines.push(Self::message_line(
+                FIELD_TO_INFO.monthly_cost,
+                format!("{:.0}", monthly_cost.total()),
+            ));
+            for (title, euros) in monthly_cost.breakdown() {
+                lines.push(MessageLine::Item {
+                    title: Some(title),
+                    value: format!("{:.0} €/kk", euros),
+                });
+            }
+        }
+
+        if let Some(purchase_cost) = &self.purchase_cost {
+            lines.push(Self::message_line(
+                FIELD_TO_INFO.purchase_cost,
+                format!("{:.0}", purchase_cost.total()),
+            ));
+            for (title, euros) in purchase_cost.breakdown() {
+                lines.push(MessageLine::Item {
+                    title: Some(title),
+                    value: format!("{:.0} €", euros),
+                });
+            }
+        }
+
+        lines.push(Self::message_line(
+            FIELD_TO_INFO.score,
+            format!("{:.2}", self.score.total()),
+        ));
+        for (title, points) in self.score.breakdown() {
+            lines.push(MessageLine::Item {
+                title: Some(title),
+                value: format!("{:.2}", points),
+            });
+        }
+
+        lines
+    }
+
+    /// Generate message.
+    pub(super) fn message(&self) -> std::string::String {
+        let mut message: std::string::String = format!("{}:", self.url);
+        for line in self.message_lines() {
+            message.push_str(&match line {
+                MessageLine::Field { title, value } => format!("\n\t{}: {}", title, value),
+                MessageLine::Item {
+                    title: Some(title),
+                    value,
+                } => format!("\n\t- {}: {}", title, value),
+                MessageLine::Item { title: None, value } => format!("\n\t- {}", value),
+            });
+        }
         return message;
     }
 
+    /// Generate HTML formatted message for Telegram.
+    /// Address links to the listing and field titles are bold.
+    pub(super) fn message_html(&self) -> std::string::String {
+        let mut html: std::string::String = format!(
+            "<b><a href=\"{}\">{}</a></b>",
+            crate::html::escape(&self.url),
+            crate::html::escape(&self.address)
+        );
+        for line in self.message_lines() {
+            html.push_str(&match line {
+                MessageLine::Field { title, value } => format!(
+                    "\n\t<b>{}</b>: {}",
+                    crate::html::escape(title),
+                    crate::html::escape(&value)
+                ),
+                MessageLine::Item {
+                    title: Some(title),
+                    value,
+                } => format!(
+                    "\n\t- {}: {}",
+                    crate::html::escape(title),
+                    crate::html::escape(&value)
+                ),
+                MessageLine::Item { title: None, value } => {
+                    format!("\n\t- {}", crate::html::escape(&value))
+                }
+            });
+        }
+        html
+    }
+
+    /// Result formatted for notifiers.
+    ///
+    /// # Arguments
+    /// * `extra` - Extra lines for the message.
+    /// * `urgent_min_score` - Minimum score for house to be sent immediately even during quiet hours.
+    pub(super) fn notification(
+        &self,
+        extra: &std::primitive::str,
+        urgent_min_score: std::option::Option<std::primitive::f64>,
+    ) -> super::HouseNotification {
+        super::HouseNotification {
+            url: self.url.clone(),
+            address: self.address.clone(),
+            message: format!("{}{}", self.message(), extra),
+            message_html: format!("{}{}", self.message_html(), crate::html::escape(extra)),
+            summary_html: self.summary_html(),
+            row_html: self.row_html(),
+            photo_url: self.photo_url.clone(),
+            location: self.location.clone(),
+            json: self.json(),
+            urgent: match urgent_min_score {
+                Some(urgent_min_score) => urgent_min_score <= self.score.total(),
+                None => false,
+            },
+        }
+    }
+
+    /// Result record as JSON, the same as in JSON output.
+    pub(super) fn json(&self) -> serde_json::Value {
+        // Record has only string keys, so serializing it can not fail.
+        serde_json::to_value(self.record()).unwrap_or_default()
+    }
+
+    /// Generate HTML formatted summary line for Telegram digest.
+    pub(super) fn summary_html(&self) -> std::string::String {
+        let mut summary: std::string::String = format!(
+            "<a href=\"{}\">{}</a>",
+            crate::html::escape(&self.url),
+            crate::html::escape(&self.address)
+        );
+        if let Some(thousands_of_euros) = self.thousands_of_euros() {
+            summary.push_str(&format!(", {} k€", thousands_of_euros));
+        }
+        if let Some(square_meters_house) = self.square_meters_house {
+            summary.push_str(&format!(", {} m²", square_meters_house));
+        }
+        if let Some(monthly_cost) = &self.monthly_cost {
+            summary.push_str(&format!(", {:.0} €/kk", monthly_cost.total()));
+        }
+        summary.push_str(&format!(", score {:.2}", self.score.total()));
+        summary
+    }
+
+    /// Generate HTML table row with same columns as CSV.
+    pub(self) fn row_html(&self) -> std::string::String {
+        // First cell is the URL, that is shown as link with address.
+        let mut html: std::string::String = format!(
+            "<tr><td><a href=\"{}\">{}</a></td>",
+            crate::html::escape(&self.url),
+            crate::html::escape(&self.address)
+        );
+        for cell in self.csv_row().iter().skip(1) {
+            html.push_str(&format!("<td>{}</td>", crate::html::escape(cell)));
+        }
+        html.push_str("</tr>");
+        html
+    }
+
+    /// Generate HTML table of houses for email, with same columns as CSV.
+    ///
+    /// # Arguments
+    /// * `houses` - Houses formatted for notifiers.
+    pub(super) fn table_html(houses: &[super::HouseNotification]) -> std::string::String {
+        let mut html: std::string::String =
+            "<table border=\"1\" cellpadding=\"4\">\n<tr>".to_string();
+        for title in Self::csv_title_row() {
+            html.push_str(&format!("<th>{}</th>", crate::html::escape(&title)));
+        }
+        html.push_str("</tr>");
+        for house in houses {
+            html.push_str(&format!("\n{}", house.row_html));
+        }
+        html.push_str("\n</table>");
+        html
+    }
+
     /// Generate CSV title row cell.
     ///
     /// # Arguments
@@ -236,9 +499,20 @@ impl Result {
         )
     }
 
+    /// Field information for distance to nearest amenity.
+    ///
+    /// # Arguments
+    /// * `amenity` - Amenity.
+    pub(self) fn amenity_info(amenity: crate::app::Amenity) -> FieldInfo {
+        FieldInfo {
+            title: amenity.title(),
+            unit: Some("km"),
+        }
+    }
+
     /// Generate CSV title row.
-    pub(super) fn csv_title_row() -> [std::string::String; 11] {
-        [
+    pub(super) fn csv_title_row() -> std::vec::Vec<std::string::String> {
+        let mut row: std::vec::Vec<std::string::String> = std::vec![
             Self::csv_title_row_cell(FIELD_TO_INFO.url),
             Self::csv_title_row_cell(FIELD_TO_INFO.thousands_of_euros),
             Self::csv_title_row_cell(FIELD_TO_INFO.floors),
@@ -248,16 +522,27 @@ impl Result {
             Self::csv_title_row_cell(FIELD_TO_INFO.euros_per_square_meter_total),
             Self::csv_title_row_cell(FIELD_TO_INFO.km_to_location_straight),
             Self::csv_title_row_cell(FIELD_TO_INFO.km_to_location_biking),
+            Self::csv_title_row_cell(FIELD_TO_INFO.minutes_to_location_transit),
             Self::csv_title_row_cell(FIELD_TO_INFO.year),
-            Self::csv_title_row_cell(FIELD_TO_INFO.internet_strings),
-        ]
+            Self::csv_title_row_cell(FIELD_TO_INFO.best_mbps),
+            Self::csv_title_row_cell(FIELD_TO_INFO.cheapest_internet_euros_per_month),
+            Self::csv_title_row_cell(FIELD_TO_INFO.internet_offers),
+            Self::csv_title_row_cell(FIELD_TO_INFO.mobile_network),
+            Self::csv_title_row_cell(FIELD_TO_INFO.monthly_cost),
+            Self::csv_title_row_cell(FIELD_TO_INFO.purchase_cost),
+            Self::csv_title_row_cell(FIELD_TO_INFO.score),
+        ];
+        for amenity in crate::app::Amenity::ALL {
+            row.push(Self::csv_title_row_cell(Self::amenity_info(amenity)));
+        }
+        row
     }
 
     /// Generate CSV row.
-    pub(super) fn csv_row(&self) -> [std::string::String; 11] {
-        [
+    pub(super) fn csv_row(&self) -> std::vec::Vec<std::string::String> {
+        let mut row: std::vec::Vec<std::string::String> = std::vec![
             self.url.clone(),
-            match self.thousands_of_euros {
+            match self.thousands_of_euros() {
                 Some(thousands_of_euros) => thousands_of_euros.to_string(),
                 None => "".to_string(),
             },
@@ -289,64 +574,298 @@ impl Result {
                 Some(km_to_location_biking) => km_to_location_biking.to_string(),
                 None => "".to_string(),
             },
+            match self.minutes_to_location_transit {
+                Some(minutes_to_location_transit) => minutes_to_location_transit.to_string(),
+                None => "".to_string(),
+            },
             match self.year {
                 Some(year) => year.to_string(),
                 None => "".to_string(),
             },
-            self.internet_strings
-                .iter()
-                .map(|internet_string| format!("\n{}", internet_string))
-                .collect::<std::string::String>(),
-        ]
+            match self.best_mbps {
+                Some(best_mbps) => best_mbps.to_string(),
+                None => "".to_string(),
+            },
+            match self.cheapest_internet_euros_per_month {
+                Some(cheapest_internet_euros_per_month) => {
+                    format!("{:.2}", cheapest_internet_euros_per_month)
+                }
+                None => "".to_string(),
+            },
+            match &self.internet_offers {
+                Some(internet_offers) => internet_offers
+                    .iter()
+                    .map(|internet_offer| internet_offer.to_str())
+                    .collect::<std::vec::Vec<std::string::String>>()
+                    .join("; "),
+                None => "unknown".to_string(),
+            },
+            match &self.mobile_network {
+                Some(mobile_network) => mobile_network.to_str(),
+                None => "".to_string(),
+            },
+            match &self.monthly_cost {
+                Some(monthly_cost) => format!("{:.0}", monthly_cost.total()),
+                None => "".to_string(),
+            },
+            match &self.purchase_cost {
+                Some(purchase_cost) => format!("{:.0}", purchase_cost.total()),
+                None => "".to_string(),
+            },
+            format!("{:.2}", self.score.total()),
+        ];
+        for amenity in crate::app::Amenity::ALL {
+            row.push(match self.km_to_amenities.get(&amenity) {
+                Some(km_to_amenity) => format!("{:.1}", km_to_amenity),
+                None => "".to_string(),
+            });
+        }
+        row
     }
 
-    /// Write CSV file.
+    /// Generate path for new output file in the same directory as executable.
     ///
     /// # Arguments
-    /// * `results` - Results.
-    ///
-    /// # Returns
-    /// Path to the CSV file.
-    pub(super) fn write_csv(
-        results: &std::vec::Vec<Self>,
+    /// * `name` - Name prefix for the file.
+    /// * `ext` - Extension for the file.
+    pub(super) fn output_path(
+        name: &std::primitive::str,
+        ext: &std::primitive::str,
     ) -> std::result::Result<std::string::String, std::io::Error> {
         let mut exe_dir: std::path::PathBuf = std::env::current_exe()?;
         let _: bool = exe_dir.pop();
-        let path: std::string::String = exe_dir
+        Ok(exe_dir
             .join(format!(
-                "{}.csv",
+                "{}.{}",
                 format!(
-                    "results_{}_{}",
+                    "{}_{}_{}",
+                    name,
                     chrono::Local::now().format("%Y%m%d_%H%M%S").to_string(),
                     rand::RngCore::next_u64(&mut rand::rng())
-                )
+                ),
+                ext
             ))
             .to_str()
             .ok_or(std::io::Error::new(
                 std::io::ErrorKind::InvalidData,
-                "Failed to convert CSV path to string.",
+                "Failed to convert output path to string.",
             ))?
-            .to_string();
-
-        let mut writer: csv::Writer<std::fs::File> = csv::Writer::from_writer(
-            std::fs::OpenOptions::new()
-                .write(true)
-                .create(true)
-                .append(false)
-                .create_new(true)
-                .open(&path)?,
-        );
-        writer.write_record(&Self::csv_title_row())?;
+            .to_string())
+    }
+
+    /// Create new output file.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the file.
+    pub(super) fn output_file(
+        path: &std::primitive::str,
+    ) -> std::result::Result<std::fs::File, std::io::Error> {
+        std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+    }
+
+    /// Write CSV file.
+    ///
+    /// # Arguments
+    /// * `results` - Results.
+    ///
+    /// # Returns
+    /// Path to the CSV file.
+    pub(super) fn write_csv(
+        results: &[Self],
+    ) -> std::result::Result<std::string::String, std::io::Error> {
+        let path: std::string::String = Self::output_path("results", "csv")?;
+        Self::write_csv_to(results, Self::output_file(&path)?, true)?;
+
+        Ok(path)
+    }
+
+    /// Write results as CSV.
+    ///
+    /// # Arguments
+    /// * `results` - Results.
+    /// * `writer` - Writer to write CSV to.
+    /// * `title_row` - Write title row before the results?
+    pub(super) fn write_csv_to<W: std::io::Write>(
+        results: &[Self],
+        writer: W,
+        title_row: std::primitive::bool,
+    ) -> std::result::Result<(), std::io::Error> {
+        let mut writer: csv::Writer<W> = csv::Writer::from_writer(writer);
+        if title_row {
+            writer.write_record(Self::csv_title_row())?;
+        }
         for result in results {
-            writer.write_record(&result.csv_row())?;
+            writer.write_record(result.csv_row())?;
         }
         writer.flush()?;
+        Ok(())
+    }
 
-        return Ok(path);
+    /// Result as record of JSON and JSON Lines output.
+    pub(super) fn record(&self) -> super::ResultRecord<'_> {
+        super::ResultRecord {
+            schema_version: super::SCHEMA_VERSION,
+            url: &self.url,
+            address: &self.address,
+            euros: self.euros,
+            floors: self.floors,
+            square_meters_house: self.square_meters_house,
+            euros_per_square_meter_house: self.euros_per_square_meter_house,
+            square_meters_total: self.square_meters_total,
+            euros_per_square_meter_total: self.euros_per_square_meter_total,
+            km_to_location_straight: self.km_to_location_straight,
+            km_to_location_biking: self.km_to_location_biking,
+            minutes_to_location_transit: self.minutes_to_location_transit,
+            year: self.year,
+            km_to_amenities: self
+                .km_to_amenities
+                .iter()
+                .map(|(amenity, km)| (amenity.name(), *km))
+                .collect(),
+            best_mbps: self.best_mbps,
+            cheapest_internet_euros_per_month: self.cheapest_internet_euros_per_month,
+            internet_offers: self
+                .internet_offers
+                .as_ref()
+                .map(|offers| offers.iter().map(super::InternetOfferRecord::new).collect()),
+            mobile_network: self.mobile_network.as_ref().map(super::NetworkRecord::new),
+            monthly_cost: self
+                .monthly_cost
+                .as_ref()
+                .map(super::MonthlyCostRecord::new),
+            monthly_cost_total: self.monthly_cost.map(|monthly_cost| monthly_cost.total()),
+            purchase_cost: self
+                .purchase_cost
+                .as_ref()
+                .map(super::PurchaseCostRecord::new),
+            purchase_cost_total: self
+                .purchase_cost
+                .map(|purchase_cost| purchase_cost.total()),
+            score: super::ScoreRecord::new(&self.score),
+            photo_url: self.photo_url.as_deref(),
+            latitude: self.location.as_ref().map(|location| location.latitude),
+            longitude: self.location.as_ref().map(|location| location.longitude),
+        }
     }
 
-    /// Generate a key for sorting.
-    pub(super) fn sort_key(&self) -> std::primitive::u32 {
-        self.euros_per_square_meter_house.unwrap_or(0)
+    /// Write internet offers of every result to CSV file, one offer per row.
+    ///
+    /// # Arguments
+    /// * `results` - Results.
+    ///
+    /// # Returns
+    /// Path to the CSV file.
+    pub(super) fn write_internet_offers_csv(
+        results: &[Self],
+    ) -> std::result::Result<std::string::String, std::io::Error> {
+        let path: std::string::String = Self::output_path("internet_offers", "csv")?;
+        let mut writer: csv::Writer<std::fs::File> =
+            csv::Writer::from_writer(Self::output_file(&path)?);
+        writer.write_record([
+            "URL",
+            "Provider",
+            "Name",
+            "Technology",
+            "Download Mbit/s",
+            "Upload Mbit/s",
+            "Price €/kk",
+            "Delivery date",
+        ])?;
+        for result in results {
+            for internet_offer in result.internet_offers.iter().flatten() {
+                writer.write_record(&[
+                    result.url.clone(),
+                    internet_offer.provider.clone(),
+                    internet_offer.name.clone(),
+                    internet_offer.technology.clone(),
+                    internet_offer.mbps_down.to_string(),
+                    match internet_offer.mbps_up {
+                        Some(mbps_up) => mbps_up.to_string(),
+                        None => "".to_string(),
+                    },
+                    format!("{:.2}", internet_offer.euros_per_month),
+                    internet_offer.delivery_date.clone(),
+                ])?;
+            }
+        }
+        writer.flush()?;
+
+        Ok(path)
+    }
+
+    /// Write internet offers of every result to JSON file.
+    /// Offers are null if internet availability is unknown.
+    ///
+    /// # Arguments
+    /// * `results` - Results.
+    ///
+    /// # Returns
+    /// Path to the JSON file.
+    pub(super) fn write_internet_offers_json(
+        results: &[Self],
+    ) -> std::result::Result<std::string::String, std::io::Error> {
+        let path: std::string::String = Self::output_path("internet_offers", "json")?;
+        serde_json::to_writer_pretty(
+            Self::output_file(&path)?,
+            &results
+                .iter()
+                .map(|result| {
+                    serde_json::json!({
+                        "url": result.url,
+                        "offers": result.internet_offers.as_ref().map(|offers| {
+                            offers
+                                .iter()
+                                .map(super::InternetOfferRecord::new)
+                                .collect::<std::vec::Vec<super::InternetOfferRecord<'_>>>()
+                        }),
+                    })
+                })
+                .collect::<std::vec::Vec<serde_json::Value>>(),
+        )?;
+
+        Ok(path)
+    }
+
+    /// Generate a key for sorting, smaller is better.
+    ///
+    /// # Arguments
+    /// * `sort_by` - Sort by, one of: SCORE, PRICE, PRICE_PER_SQUARE_METER, MONTHLY_COST, DISTANCE or TRANSIT
+    pub(self) fn sort_key(
+        &self,
+        sort_by: &std::primitive::str,
+    ) -> std::option::Option<std::primitive::f64> {
+        match sort_by {
+            "SCORE" => Some(-self.score.total()),
+            "PRICE" => self.euros.map(|euros| euros as std::primitive::f64),
+            "MONTHLY_COST" => self.monthly_cost.map(|monthly_cost| monthly_cost.total()),
+            "DISTANCE" => self
+                .km_to_location_biking
+                .or(self.km_to_location_straight)
+                .map(|km| km as std::primitive::f64),
+            "TRANSIT" => self
+                .minutes_to_location_transit
+                .map(|minutes| minutes as std::primitive::f64),
+            _ => self
+                .euros_per_square_meter_house
+                .map(|euros| euros as std::primitive::f64),
+        }
+    }
+
+    /// Compare results for sorting, results with unknown sort key are last.
+    ///
+    /// # Arguments
+    /// * `a` - First result.
+    /// * `b` - Second result.
+    /// * `sort_by` - Sort by, one of: SCORE, PRICE, PRICE_PER_SQUARE_METER, MONTHLY_COST, DISTANCE or TRANSIT
+    pub(super) fn compare(a: &Self, b: &Self, sort_by: &std::primitive::str) -> std::cmp::Ordering {
+        match (a.sort_key(sort_by), b.sort_key(sort_by)) {
+            (Some(a), Some(b)) => a.total_cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
     }
 }
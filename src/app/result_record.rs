@@ -0,0 +1,217 @@
+/// Result as a record of JSON and JSON Lines output, with raw values.
+/// Fields are only added in the same schema version, removing or changing them increases the version.
+#[derive(serde::Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ResultRecord<'a> {
+    pub(super) schema_version: std::primitive::u32,
+    pub(super) url: &'a std::primitive::str,
+    pub(super) address: &'a std::primitive::str,
+    pub(super) euros: std::option::Option<std::primitive::u32>,
+    pub(super) floors: std::option::Option<std::primitive::u8>,
+    pub(super) square_meters_house: std::option::Option<std::primitive::u16>,
+    pub(super) euros_per_square_meter_house: std::option::Option<std::primitive::u32>,
+    pub(super) square_meters_total: std::option::Option<std::primitive::u16>,
+    pub(super) euros_per_square_meter_total: std::option::Option<std::primitive::u32>,
+    pub(super) km_to_location_straight: std::option::Option<std::primitive::u16>,
+    pub(super) km_to_location_biking: std::option::Option<std::primitive::u16>,
+    pub(super) minutes_to_location_transit: std::option::Option<std::primitive::u16>,
+    pub(super) year: std::option::Option<std::primitive::u16>,
+    /// Distances to nearest amenities by amenity argument value.
+    pub(super) km_to_amenities:
+        std::collections::BTreeMap<&'static std::primitive::str, std::primitive::f64>,
+    pub(super) best_mbps: std::option::Option<std::primitive::u32>,
+    pub(super) cheapest_internet_euros_per_month: std::option::Option<std::primitive::f32>,
+    /// None if internet availability is unknown.
+    pub(super) internet_offers: std::option::Option<std::vec::Vec<InternetOfferRecord<'a>>>,
+    pub(super) mobile_network: std::option::Option<NetworkRecord<'a>>,
+    pub(super) monthly_cost: std::option::Option<MonthlyCostRecord>,
+    pub(super) monthly_cost_total: std::option::Option<std::primitive::f64>,
+    pub(super) purchase_cost: std::option::Option<PurchaseCostRecord>,
+    pub(super) purchase_cost_total: std::option::Option<std::primitive::f64>,
+    pub(super) score: ScoreRecord,
+    pub(super) photo_url: std::option::Option<&'a std::primitive::str>,
+    pub(super) latitude: std::option::Option<std::primitive::f64>,
+    pub(super) longitude: std::option::Option<std::primitive::f64>,
+}
+
+/// Internet offer in result record.
+#[derive(serde::Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct InternetOfferRecord<'a> {
+    pub(super) provider: &'a std::primitive::str,
+    pub(super) name: &'a std::primitive::str,
+    pub(super) technology: &'a std::primitive::str,
+    pub(super) mbps_down: std::primitive::u32,
+    pub(super) mbps_up: std::option::Option<std::primitive::u32>,
+    pub(super) euros_per_month: std::primitive::f32,
+    pub(super) delivery_date: &'a std::primitive::str,
+}
+
+impl<'a> InternetOfferRecord<'a> {
+    /// Create record from internet offer.
+    ///
+    /// # Arguments
+    /// * `offer` - Internet offer.
+    pub(super) fn new(offer: &'a super::InternetOffer) -> Self {
+        Self {
+            provider: &offer.provider,
+            name: &offer.name,
+            technology: &offer.technology,
+            mbps_down: offer.mbps_down,
+            mbps_up: offer.mbps_up,
+            euros_per_month: offer.euros_per_month,
+            delivery_date: &offer.delivery_date,
+        }
+    }
+}
+
+/// Mobile network in result record.
+#[derive(serde::Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NetworkRecord<'a> {
+    pub(super) operator: &'a std::primitive::str,
+    pub(super) technology: &'a std::primitive::str,
+    pub(super) speed_class: &'a std::primitive::str,
+    pub(super) mbps: std::option::Option<std::primitive::u32>,
+}
+
+impl<'a> NetworkRecord<'a> {
+    /// Create record from mobile network.
+    ///
+    /// # Arguments
+    /// * `network` - Mobile network.
+    pub(super) fn new(network: &'a crate::coverage::Network) -> Self {
+        Self {
+            operator: &network.operator,
+            technology: &network.technology,
+            speed_class: &network.speed_class,
+            mbps: network.mbps,
+        }
+    }
+}
+
+/// Monthly cost in euros in result record.
+#[derive(serde::Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MonthlyCostRecord {
+    pub(super) mortgage: std::primitive::f64,
+    pub(super) property_tax: std::primitive::f64,
+    pub(super) heating: std::primitive::f64,
+    pub(super) internet: std::primitive::f64,
+    pub(super) commute: std::primitive::f64,
+}
+
+impl MonthlyCostRecord {
+    /// Create record from monthly cost.
+    ///
+    /// # Arguments
+    /// * `monthly_cost` - Monthly cost.
+    pub(super) fn new(monthly_cost: &super::MonthlyCost) -> Self {
+        Self {
+            mortgage: monthly_cost.mortgage,
+            property_tax: monthly_cost.property_tax,
+            heating: monthly_cost.heating,
+            internet: monthly_cost.internet,
+            commute: monthly_cost.commute,
+        }
+    }
+}
+
+/// One-off purchase cost in euros in result record.
+#[derive(serde::Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PurchaseCostRecord {
+    pub(super) transfer_tax: std::primitive::f64,
+    pub(super) registration_fees: std::primitive::f64,
+    pub(super) moving_renovation: std::primitive::f64,
+}
+
+impl PurchaseCostRecord {
+    /// Create record from purchase cost.
+    ///
+    /// # Arguments
+    /// * `purchase_cost` - Purchase cost.
+    pub(super) fn new(purchase_cost: &super::PurchaseCost) -> Self {
+        Self {
+            transfer_tax: purchase_cost.transfer_tax,
+            registration_fees: purchase_cost.registration_fees,
+            moving_renovation: purchase_cost.moving_renovation,
+        }
+    }
+}
+
+/// Score in result record.
+#[derive(serde::Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ScoreRecord {
+    pub(super) total: std::primitive::f64,
+    pub(super) breakdown: ScoreBreakdownRecord,
+}
+
+/// Weighted points of the score values in result record.
+#[derive(serde::Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ScoreBreakdownRecord {
+    pub(super) price_per_square_meter: std::primitive::f64,
+    pub(super) commute: std::primitive::f64,
+    pub(super) year: std::primitive::f64,
+    pub(super) total_area: std::primitive::f64,
+    pub(super) internet: std::primitive::f64,
+    pub(super) energy_class: std::primitive::f64,
+    pub(super) keywords: std::primitive::f64,
+}
+
+impl ScoreRecord {
+    /// Create record from score.
+    ///
+    /// # Arguments
+    /// * `score` - Score.
+    pub(super) fn new(score: &super::Score) -> Self {
+        Self {
+            total: score.total(),
+            breakdown: ScoreBreakdownRecord {
+                price_per_square_meter: score.price_per_square_meter,
+                commute: score.commute,
+                year: score.year,
+                total_area: score.total_area,
+                internet: score.internet,
+                energy_class: score.energy_class,
+                keywords: score.keywords,
+            },
+        }
+    }
+}
+
+/// Version of the output schema.
+pub(super) const SCHEMA_VERSION: std::primitive::u32 = 1;
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn score_breakdown_has_named_keys() {
+        let score: crate::app::Score = crate::app::Score {
+            price_per_square_meter: 1.0,
+            commute: 0.5,
+            year: 0.0,
+            total_area: 0.0,
+            internet: 0.25,
+            energy_class: 0.0,
+            keywords: 0.0,
+        };
+        assert_eq!(
+            serde_json::to_value(super::ScoreRecord::new(&score)).unwrap(),
+            serde_json::json!({
+                "total": 1.75,
+                "breakdown": {
+                    "pricePerSquareMeter": 1.0,
+                    "commute": 0.5,
+                    "year": 0.0,
+                    "totalArea": 0.0,
+                    "internet": 0.25,
+                    "energyClass": 0.0,
+                    "keywords": 0.0,
+                },
+            })
+        );
+    }
+}
@@ -0,0 +1,20 @@
+/// Trait to define routing service.
+pub(crate) trait Routing: Send + Sync {
+    /// Biking distance in kilometers between two locations.
+    ///
+    /// # Arguments
+    /// * `from` - Starting location.
+    /// * `to` - Destination location.
+    fn biking_km<'a>(
+        &'a self,
+        from: longitude::Location,
+        to: longitude::Location,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<
+                    Output = std::result::Result<std::primitive::u16, crate::client::JSONError>,
+                > + Send
+                + 'a,
+        >,
+    >;
+}
@@ -0,0 +1,31 @@
+/// Search criteria saved for Telegram chat, overriding command line arguments.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SavedSearch {
+    pub(super) price_max: std::option::Option<std::primitive::u32>,
+    pub(super) cities: std::option::Option<std::vec::Vec<std::string::String>>,
+    /// Send results also by email to these addresses instead of --email-to.
+    #[serde(default)]
+    pub(super) email_to: std::option::Option<std::vec::Vec<std::string::String>>,
+}
+
+/// Name of the store for saved searches.
+pub(super) const SAVED_SEARCHES: &std::primitive::str = "saved_searches";
+
+impl SavedSearch {
+    /// Apply saved search to arguments.
+    ///
+    /// # Arguments
+    /// * `args` - Application arguments to apply to.
+    pub(super) fn apply(&self, args: &mut super::Args) {
+        if let Some(price_max) = self.price_max {
+            args.price_max = Some(price_max);
+        }
+        if let Some(cities) = &self.cities {
+            args.cities = cities.clone();
+        }
+        if let Some(email_to) = &self.email_to {
+            args.email_to = email_to.clone();
+        }
+    }
+}
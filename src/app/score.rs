@@ -0,0 +1,185 @@
+/// Weights for scoring houses.
+#[derive(Debug, Clone)]
+pub(crate) struct ScoreWeights {
+    pub(super) price_per_square_meter: std::primitive::f64,
+    pub(super) commute: std::primitive::f64,
+    pub(super) year: std::primitive::f64,
+    pub(super) total_area: std::primitive::f64,
+    pub(super) internet: std::primitive::f64,
+    pub(super) energy_class: std::primitive::f64,
+    pub(super) keywords: std::primitive::f64,
+    pub(super) keyword_texts: std::vec::Vec<std::string::String>,
+}
+
+/// House values for scoring, unknown values score 0.
+pub(crate) struct ScoreValues {
+    /// Price per square meter.
+    pub(super) euros_per_square_meter: std::option::Option<std::primitive::u32>,
+    /// Public transport journey time in minutes to location.
+    pub(super) transit_minutes: std::option::Option<std::primitive::u16>,
+    /// Biking distance in kilometers to location.
+    pub(super) biking_km: std::option::Option<std::primitive::u16>,
+    /// Construction year.
+    pub(super) year: std::option::Option<std::primitive::u16>,
+    /// Total square meters of the property.
+    pub(super) square_meters_total: std::option::Option<std::primitive::u16>,
+    /// Best internet speed in megabits per second.
+    pub(super) best_mbps: std::option::Option<std::primitive::u32>,
+    /// Energy class.
+    pub(super) energy_class: std::option::Option<std::string::String>,
+    /// Announcement text.
+    pub(super) text: std::option::Option<std::string::String>,
+}
+
+/// Weighted score for a house, higher is better.
+/// Every field is weighted points of the value.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Score {
+    pub(super) price_per_square_meter: std::primitive::f64,
+    pub(super) commute: std::primitive::f64,
+    pub(super) year: std::primitive::f64,
+    pub(super) total_area: std::primitive::f64,
+    pub(super) internet: std::primitive::f64,
+    pub(super) energy_class: std::primitive::f64,
+    pub(super) keywords: std::primitive::f64,
+}
+
+/// Price per square meter in euros that scores zero.
+const WORST_EUROS_PER_SQUARE_METER: std::primitive::f64 = 5000.0;
+
+/// Commute time in minutes that scores zero.
+const WORST_COMMUTE_MINUTES: std::primitive::f64 = 120.0;
+
+/// Minutes per kilometer when biking, used if public transport time is unknown.
+const BIKING_MINUTES_PER_KM: std::primitive::f64 = 4.0;
+
+/// Construction year that scores zero.
+const WORST_YEAR: std::primitive::f64 = 1950.0;
+
+/// Total floor area in square meters that scores full.
+const BEST_TOTAL_SQUARE_METERS: std::primitive::f64 = 250.0;
+
+/// Internet speed in megabits per second that scores full.
+const BEST_MBPS: std::primitive::f64 = 1000.0;
+
+impl Score {
+    /// Calculate score from house values.
+    /// Every value is scaled between 0 and 1 before weighting, unknown values score 0.
+    ///
+    /// # Arguments
+    /// * `weights` - Weights for the values.
+    /// * `values` - House values.
+    pub(super) fn new(weights: &ScoreWeights, values: ScoreValues) -> Self {
+        let ScoreValues {
+            euros_per_square_meter,
+            transit_minutes,
+            biking_km,
+            year,
+            square_meters_total,
+            best_mbps,
+            energy_class,
+            text,
+        } = values;
+        let commute_minutes: std::option::Option<std::primitive::f64> = match transit_minutes {
+            Some(transit_minutes) => Some(transit_minutes as std::primitive::f64),
+            None => {
+                biking_km.map(|biking_km| biking_km as std::primitive::f64 * BIKING_MINUTES_PER_KM)
+            }
+        };
+        let current_year: std::primitive::f64 =
+            chrono::Datelike::year(&chrono::Local::now()) as std::primitive::f64;
+        Self {
+            price_per_square_meter: weights.price_per_square_meter
+                * euros_per_square_meter
+                    .map(|euros| {
+                        1.0 - Self::scale(
+                            euros as std::primitive::f64 / WORST_EUROS_PER_SQUARE_METER,
+                        )
+                    })
+                    .unwrap_or(0.0),
+            commute: weights.commute
+                * commute_minutes
+                    .map(|minutes| 1.0 - Self::scale(minutes / WORST_COMMUTE_MINUTES))
+                    .unwrap_or(0.0),
+            year: weights.year
+                * year
+                    .map(|year| {
+                        Self::scale(
+                            (year as std::primitive::f64 - WORST_YEAR)
+                                / (current_year - WORST_YEAR),
+                        )
+                    })
+                    .unwrap_or(0.0),
+            total_area: weights.total_area
+                * square_meters_total
+                    .map(|square_meters| {
+                        Self::scale(square_meters as std::primitive::f64 / BEST_TOTAL_SQUARE_METERS)
+                    })
+                    .unwrap_or(0.0),
+            internet: weights.internet
+                * best_mbps
+                    .map(|mbps| {
+                        Self::scale(
+                            (mbps as std::primitive::f64).max(1.0).log10() / BEST_MBPS.log10(),
+                        )
+                    })
+                    .unwrap_or(0.0),
+            energy_class: weights.energy_class
+                * match energy_class
+                    .and_then(|energy_class| energy_class.to_uppercase().chars().next())
+                {
+                    Some(class @ 'A'..='G') => {
+                        ((b'G' - class as std::primitive::u8) as std::primitive::f64) / 6.0
+                    }
+                    _ => 0.0,
+                },
+            keywords: weights.keywords
+                * match text {
+                    Some(text) if !weights.keyword_texts.is_empty() => {
+                        let text_lowercase: std::string::String = text.to_lowercase();
+                        weights
+                            .keyword_texts
+                            .iter()
+                            .filter(|keyword| text_lowercase.contains(keyword.as_str()))
+                            .count() as std::primitive::f64
+                            / weights.keyword_texts.len() as std::primitive::f64
+                    }
+                    _ => 0.0,
+                },
+        }
+    }
+
+    /// Total score.
+    pub(crate) fn total(&self) -> std::primitive::f64 {
+        self.price_per_square_meter
+            + self.commute
+            + self.year
+            + self.total_area
+            + self.internet
+            + self.energy_class
+            + self.keywords
+    }
+
+    /// Breakdown of the score.
+    pub(crate) fn breakdown(
+        &self,
+    ) -> std::vec::Vec<(&'static std::primitive::str, std::primitive::f64)> {
+        std::vec![
+            ("Price/m²", self.price_per_square_meter),
+            ("Commute", self.commute),
+            ("Year", self.year),
+            ("Total area", self.total_area),
+            ("Internet", self.internet),
+            ("Energy class", self.energy_class),
+            ("Keywords", self.keywords),
+        ]
+    }
+
+    /// Clamp value between 0 and 1.
+    ///
+    /// # Arguments
+    /// * `value` - Value to clamp.
+    pub(self) fn scale(value: std::primitive::f64) -> std::primitive::f64 {
+        value.clamp(0.0, 1.0)
+    }
+}
@@ -0,0 +1,82 @@
+/// Generic broadband provider, for example adapter for DNA, Telia or local fiber co-op.
+/// Provider is queried from URL template that returns JSON array of products in format:
+/// `[{"name": "...", "technology": "...", "eurosPerMonth": 0.0, "mbps": 0, "mbpsUp": 0, "deliveryDate": "..."}]`
+/// where `mbpsUp` is optional.
+pub(crate) struct Broadband {
+    pub(self) name: std::string::String,
+    pub(self) url_template: std::string::String,
+    pub(self) cache_products: std::primitive::bool,
+    pub(self) limiter: std::sync::Arc<tokio::sync::Mutex<crate::client::BetweenCalls>>,
+}
+
+impl Broadband {
+    /// Create a new broadband provider with it's own rate limiter.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the provider.
+    /// * `url_template` - URL with `{postal_code}` and `{street_address}` placeholders.
+    /// * `cache_products` - Cache products?
+    pub(crate) fn new(
+        name: &std::primitive::str,
+        url_template: &std::primitive::str,
+        cache_products: std::primitive::bool,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            url_template: url_template.to_string(),
+            cache_products,
+            limiter: std::sync::Arc::new(tokio::sync::Mutex::new(
+                crate::client::BetweenCalls::new(5000),
+            )),
+        }
+    }
+
+    /// Name of the provider.
+    pub(crate) fn name(&self) -> &std::primitive::str {
+        &self.name
+    }
+
+    /// Encode value to be used in any part of the URL.
+    ///
+    /// # Arguments
+    /// * `value` - Value to encode.
+    pub(self) fn encode(value: &std::primitive::str) -> std::string::String {
+        // Literal plus is encoded, so remaining ones are spaces.
+        url::form_urlencoded::byte_serialize(value.as_bytes())
+            .collect::<std::string::String>()
+            .replace('+', "%20")
+    }
+
+    /// Get internet products for the address.
+    ///
+    /// # Arguments
+    /// * `postal_code` - Postal code.
+    /// * `street_address` - Street address.
+    pub(crate) async fn internets(
+        &self,
+        postal_code: &std::primitive::str,
+        street_address: &std::primitive::str,
+    ) -> std::result::Result<std::vec::Vec<super::Internet>, crate::client::JSONError> {
+        Ok(crate::client::Client::new(
+            if self.cache_products {
+                Some(format!("broadband/{}", self.name))
+            } else {
+                None
+            }
+            .as_deref(),
+            Some(std::sync::Arc::clone(&self.limiter)),
+        )?
+        .get_json::<std::vec::Vec<super::Product>>(
+            &self
+                .url_template
+                .replace("{postal_code}", &Self::encode(postal_code))
+                .replace("{street_address}", &Self::encode(street_address)),
+            None,
+        )
+        .await?
+        .into_iter()
+        .map(|product| super::Internet::new(self.name.clone(), product))
+        .filter(|internet| internet.include())
+        .collect())
+    }
+}
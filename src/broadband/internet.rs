@@ -0,0 +1,63 @@
+/// Generic broadband provider internet implementation.
+#[derive(Clone)]
+pub(crate) struct Internet {
+    pub(self) provider: std::string::String,
+    pub(self) product: super::Product,
+}
+
+impl self::Internet {
+    /// Create a new internet instance.
+    ///
+    /// # Arguments
+    /// * `provider` - Name of the provider.
+    /// * `product` - Provider product.
+    pub(super) fn new(provider: std::string::String, product: super::Product) -> Self {
+        Self { provider, product }
+    }
+
+    /// Include the internet product?
+    pub(super) fn include(&self) -> std::primitive::bool {
+        // Do not include mobile products.
+        let technology: std::string::String = self.product.technology.to_lowercase();
+        !["mobile", "wireless", "4g", "5g", "lte"]
+            .iter()
+            .any(|mobile| technology.contains(mobile))
+    }
+}
+
+impl crate::app::Internet for self::Internet {
+    /// Name of the provider.
+    fn provider(&self) -> std::string::String {
+        self.provider.clone()
+    }
+
+    /// Name for the internet product.
+    fn name(&self) -> std::string::String {
+        self.product.name.clone()
+    }
+
+    /// Technology of the internet product.
+    fn technology(&self) -> std::string::String {
+        self.product.technology.clone()
+    }
+
+    /// Price in euros per month.
+    fn euros_per_month(&self) -> std::primitive::f32 {
+        self.product.euros_per_month
+    }
+
+    /// Download speed in megabits per second.
+    fn mbps(&self) -> std::primitive::u32 {
+        self.product.mbps
+    }
+
+    /// Upload speed in megabits per second, if known.
+    fn mbps_up(&self) -> std::option::Option<std::primitive::u32> {
+        self.product.mbps_up
+    }
+
+    /// When the internet is delivered.
+    fn delivery_date(&self) -> std::string::String {
+        self.product.delivery_date.clone()
+    }
+}
@@ -0,0 +1,8 @@
+mod broadband;
+mod internet;
+mod product;
+
+pub(crate) use self::broadband::Broadband;
+pub(crate) use self::internet::Internet;
+
+use self::product::Product;
@@ -0,0 +1,11 @@
+/// Broadband product in the generic provider format.
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Product {
+    pub(super) name: std::string::String,
+    pub(super) technology: std::string::String,
+    pub(super) euros_per_month: std::primitive::f32,
+    pub(super) mbps: std::primitive::u32,
+    pub(super) mbps_up: std::option::Option<std::primitive::u32>,
+    pub(super) delivery_date: std::string::String,
+}
@@ -82,4 +82,18 @@ impl Cache {
     ) -> std::result::Result<std::string::String, std::io::Error> {
         std::fs::read_to_string(self.path(key))
     }
+
+    /// Remove cache file, if it exists.
+    ///
+    /// # Arguments
+    /// * `key` - The key to use to generate filename.
+    pub(crate) fn remove(
+        &self,
+        key: &std::primitive::str,
+    ) -> std::result::Result<(), std::io::Error> {
+        if self.exists(key) {
+            std::fs::remove_file(self.path(key))?;
+        }
+        Ok(())
+    }
 }
@@ -180,9 +180,40 @@ impl<L: super::Limiter> Client<L> {
             reqwest::header::CONTENT_TYPE,
             reqwest::header::HeaderValue::from_static("application/json"),
         );
-        return Ok(serde_json::from_str::<T>(
-            &self.text(method, url, json, Some(headers_real)).await?,
-        )?);
+        let text: std::string::String = self
+            .text(method, url, json.clone(), Some(headers_real))
+            .await?;
+        match serde_json::from_str::<T>(&text) {
+            Ok(value) => Ok(value),
+            Err(error) => {
+                // Response that can not be parsed, for example an error response, is not kept in cache,
+                // so that it is requested again next time.
+                if let Some(cache) = self.cache.as_ref() {
+                    cache
+                        .remove(&Self::cache_key(url, json))
+                        .map_err(super::RequestError::from)?;
+                }
+                Err(error.into())
+            }
+        }
+    }
+
+    /// Send JSON to the given URL without reading the response.
+    ///
+    /// # Arguments
+    /// * `method` - Method for the request.
+    /// * `url` - URL for the request.
+    /// * `json` - JSON data for the request.
+    /// * `headers` - Optional headers for the request.
+    pub(crate) async fn send_json(
+        &self,
+        method: reqwest::Method,
+        url: &std::primitive::str,
+        json: serde_json::Value,
+        headers: std::option::Option<reqwest::header::HeaderMap>,
+    ) -> std::result::Result<(), super::RequestError> {
+        let _: reqwest::Response = self.request(method, url, Some(json), headers).await?;
+        Ok(())
     }
 
     /// Get JSON from the given URL.
@@ -192,14 +223,16 @@ impl<L: super::Limiter> Client<L> {
     /// # Arguments
     /// * `T` - Type to deserialize JSON to.
     /// * `url` - URL to get JSON from.
+    /// * `headers` - Optional headers to send in addition to Content-Type: application/json.
     pub(crate) async fn get_json<T>(
         &self,
         url: &std::primitive::str,
+        headers: std::option::Option<reqwest::header::HeaderMap>,
     ) -> std::result::Result<T, super::JSONError>
     where
         T: serde::de::DeserializeOwned,
     {
-        self.json(reqwest::Method::GET, url, None, None).await
+        self.json(reqwest::Method::GET, url, None, headers).await
     }
 
     /// Get text from the given URL and extract a value using regex.
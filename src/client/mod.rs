@@ -9,6 +9,5 @@ pub(crate) use self::between_calls::BetweenCalls;
 pub(crate) use self::calls_per_minute::CallsPerMinute;
 pub(crate) use self::client::Client;
 pub(crate) use self::json_error::JSONError;
+pub(crate) use self::limiter::Limiter;
 pub(crate) use self::request_error::RequestError;
-
-pub(self) use self::limiter::Limiter;
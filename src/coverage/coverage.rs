@@ -0,0 +1,93 @@
+/// Mobile network coverage from API or local GeoJSON file, for example Traficom coverage data.
+pub(crate) struct Coverage {
+    pub(self) url_template: std::option::Option<std::string::String>,
+    pub(self) cache: std::primitive::bool,
+    pub(self) properties: super::Properties,
+    pub(self) features: std::vec::Vec<super::Feature>,
+}
+
+static LIMITER: once_cell::sync::Lazy<
+    std::sync::Arc<tokio::sync::Mutex<crate::client::BetweenCalls>>,
+> = once_cell::sync::Lazy::new(|| {
+    std::sync::Arc::new(tokio::sync::Mutex::new(crate::client::BetweenCalls::new(
+        5000,
+    )))
+});
+
+impl Coverage {
+    /// Create coverage from API.
+    /// API should return JSON array of networks as objects with properties, by default in format:
+    /// `[{"operator": "...", "technology": "5G", "speedClass": "...", "mbps": 0}]`
+    /// where `mbps` is optional.
+    ///
+    /// # Arguments
+    /// * `url_template` - URL with `{latitude}` and `{longitude}` placeholders.
+    /// * `properties` - Names of the network properties.
+    /// * `cache` - Cache API responses?
+    pub(crate) fn from_url(
+        url_template: &std::primitive::str,
+        properties: super::Properties,
+        cache: std::primitive::bool,
+    ) -> Self {
+        Self {
+            url_template: Some(url_template.to_string()),
+            cache,
+            properties,
+            features: std::vec::Vec::<super::Feature>::new(),
+        }
+    }
+
+    /// Create coverage from local GeoJSON file.
+    /// Features should be polygons or multipolygons with network as their properties.
+    ///
+    /// # Arguments
+    /// * `path` - Path to GeoJSON file.
+    /// * `properties` - Names of the network properties.
+    pub(crate) fn from_geojson(
+        path: &std::primitive::str,
+        properties: super::Properties,
+    ) -> std::result::Result<Self, crate::client::JSONError> {
+        Ok(Self {
+            url_template: None,
+            cache: false,
+            properties,
+            features: serde_json::from_str::<super::FeatureCollection>(
+                &std::fs::read_to_string(path).map_err(crate::client::RequestError::from)?,
+            )?
+            .features,
+        })
+    }
+
+    /// Best mobile network at location.
+    ///
+    /// # Arguments
+    /// * `location` - Location to check.
+    pub(crate) async fn best(
+        &self,
+        location: longitude::Location,
+    ) -> std::result::Result<std::option::Option<super::Network>, crate::client::JSONError> {
+        let networks: std::vec::Vec<super::Network> = match &self.url_template {
+            Some(url_template) => crate::client::Client::new(
+                if self.cache { Some("coverage") } else { None },
+                Some(std::sync::Arc::clone(&LIMITER)),
+            )?
+            .get_json::<std::vec::Vec<serde_json::Map<std::string::String, serde_json::Value>>>(
+                &url_template
+                    .replace("{latitude}", &location.latitude.to_string())
+                    .replace("{longitude}", &location.longitude.to_string()),
+                None,
+            )
+            .await?
+            .iter()
+            .filter_map(|properties| self.properties.network(properties))
+            .collect(),
+            None => self
+                .features
+                .iter()
+                .filter(|feature| feature.geometry.contains(&location))
+                .filter_map(|feature| self.properties.network(&feature.properties))
+                .collect(),
+        };
+        Ok(super::Network::best(networks))
+    }
+}
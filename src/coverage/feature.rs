@@ -0,0 +1,7 @@
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Feature {
+    pub(super) geometry: super::Geometry,
+    #[serde(default)]
+    pub(super) properties: serde_json::Map<std::string::String, serde_json::Value>,
+}
@@ -0,0 +1,78 @@
+/// GeoJSON geometry of the coverage area.
+/// Coordinates are given as longitude and latitude.
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub(super) enum Geometry {
+    Polygon {
+        coordinates: std::vec::Vec<std::vec::Vec<std::vec::Vec<std::primitive::f64>>>,
+    },
+    MultiPolygon {
+        coordinates:
+            std::vec::Vec<std::vec::Vec<std::vec::Vec<std::vec::Vec<std::primitive::f64>>>>,
+    },
+    /// Other geometries can not contain a location.
+    #[serde(other)]
+    Other,
+}
+
+impl Geometry {
+    /// Is the location inside the ring?
+    /// Uses ray casting.
+    ///
+    /// # Arguments
+    /// * `ring` - Ring of longitude and latitude pairs.
+    /// * `location` - Location to check.
+    pub(self) fn ring_contains(
+        ring: &[std::vec::Vec<std::primitive::f64>],
+        location: &longitude::Location,
+    ) -> std::primitive::bool {
+        let mut inside: std::primitive::bool = false;
+        let points: std::vec::Vec<(std::primitive::f64, std::primitive::f64)> = ring
+            .iter()
+            .filter(|point| 2 <= point.len())
+            .map(|point| (point[0], point[1]))
+            .collect();
+        for i in 0..points.len() {
+            let (x1, y1) = points[i];
+            let (x2, y2) = points[(i + 1) % points.len()];
+            if (location.latitude < y1) != (location.latitude < y2)
+                && location.longitude < x1 + (location.latitude - y1) / (y2 - y1) * (x2 - x1)
+            {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+
+    /// Is the location inside the polygon, outer ring and not in any of the holes?
+    ///
+    /// # Arguments
+    /// * `polygon` - Outer ring followed by holes.
+    /// * `location` - Location to check.
+    pub(self) fn polygon_contains(
+        polygon: &[std::vec::Vec<std::vec::Vec<std::primitive::f64>>],
+        location: &longitude::Location,
+    ) -> std::primitive::bool {
+        match polygon.split_first() {
+            Some((outer, holes)) => {
+                Self::ring_contains(outer, location)
+                    && !holes.iter().any(|hole| Self::ring_contains(hole, location))
+            }
+            None => false,
+        }
+    }
+
+    /// Is the location inside the geometry?
+    ///
+    /// # Arguments
+    /// * `location` - Location to check.
+    pub(super) fn contains(&self, location: &longitude::Location) -> std::primitive::bool {
+        match self {
+            Geometry::Polygon { coordinates } => Self::polygon_contains(coordinates, location),
+            Geometry::MultiPolygon { coordinates } => coordinates
+                .iter()
+                .any(|polygon| Self::polygon_contains(polygon, location)),
+            Geometry::Other => false,
+        }
+    }
+}
@@ -0,0 +1,14 @@
+mod coverage;
+mod feature;
+mod feature_collection;
+mod geometry;
+mod network;
+mod properties;
+
+pub(crate) use self::coverage::Coverage;
+pub(crate) use self::network::Network;
+pub(crate) use self::properties::Properties;
+
+use self::feature::Feature;
+use self::feature_collection::FeatureCollection;
+use self::geometry::Geometry;
@@ -0,0 +1,42 @@
+/// Mobile network coverage by operator.
+#[derive(Debug, Clone)]
+pub(crate) struct Network {
+    pub(crate) operator: std::string::String,
+    /// Technology, for example 5G or 4G.
+    pub(crate) technology: std::string::String,
+    /// Speed class, for example 300-1000 Mbit/s.
+    pub(crate) speed_class: std::string::String,
+    /// Lower bound of the speed class in megabits per second, if known.
+    pub(crate) mbps: std::option::Option<std::primitive::u32>,
+}
+
+impl Network {
+    /// Rank of the technology, higher is better.
+    pub(self) fn technology_rank(&self) -> std::primitive::u8 {
+        match self.technology.to_uppercase().as_str() {
+            "5G" => 5,
+            "4G" | "LTE" => 4,
+            "3G" => 3,
+            "2G" => 2,
+            _ => 0,
+        }
+    }
+
+    /// Best network by technology and speed.
+    ///
+    /// # Arguments
+    /// * `networks` - Networks to choose from.
+    pub(crate) fn best(networks: std::vec::Vec<Self>) -> std::option::Option<Self> {
+        networks
+            .into_iter()
+            .max_by_key(|network| (network.technology_rank(), network.mbps.unwrap_or(0)))
+    }
+
+    /// Transform to string.
+    pub(crate) fn to_str(&self) -> std::string::String {
+        format!(
+            "{}: {} ({})",
+            self.operator, self.technology, self.speed_class
+        )
+    }
+}
@@ -0,0 +1,98 @@
+/// Names of the coverage feature or API response properties that have the network.
+/// Defaults are the camelCase names of the network, coverage data from Traficom or operators
+/// can be used by mapping their own property names.
+#[derive(Debug, Clone)]
+pub(crate) struct Properties {
+    pub(crate) operator: std::string::String,
+    pub(crate) technology: std::string::String,
+    pub(crate) speed_class: std::string::String,
+    pub(crate) mbps: std::string::String,
+}
+
+impl Default for Properties {
+    fn default() -> Self {
+        Self {
+            operator: "operator".to_string(),
+            technology: "technology".to_string(),
+            speed_class: "speedClass".to_string(),
+            mbps: "mbps".to_string(),
+        }
+    }
+}
+
+impl Properties {
+    /// Set property name for the network field.
+    ///
+    /// # Arguments
+    /// * `field` - Network field, one of: OPERATOR, TECHNOLOGY, SPEED_CLASS or MBPS.
+    /// * `property` - Name of the property that has the field.
+    pub(crate) fn set(
+        &mut self,
+        field: &std::primitive::str,
+        property: &std::primitive::str,
+    ) -> std::result::Result<(), std::string::String> {
+        let name: &mut std::string::String = match field {
+            "OPERATOR" => &mut self.operator,
+            "TECHNOLOGY" => &mut self.technology,
+            "SPEED_CLASS" => &mut self.speed_class,
+            "MBPS" => &mut self.mbps,
+            _ => return Err(format!("Unknown coverage field: {}", field)),
+        };
+        *name = property.to_string();
+        Ok(())
+    }
+
+    /// Property value as text, numbers are formatted.
+    ///
+    /// # Arguments
+    /// * `properties` - Properties of the feature or API response network.
+    /// * `name` - Name of the property.
+    pub(self) fn text(
+        properties: &serde_json::Map<std::string::String, serde_json::Value>,
+        name: &std::primitive::str,
+    ) -> std::option::Option<std::string::String> {
+        match properties.get(name)? {
+            serde_json::Value::String(value) => Some(value.clone()),
+            serde_json::Value::Number(value) => Some(value.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Network from the properties.
+    /// Megabits per second are read from the lower bound of the speed class, if not given separately.
+    ///
+    /// # Arguments
+    /// * `properties` - Properties of the feature or API response network.
+    ///
+    /// # Returns
+    /// None if the properties do not have technology.
+    pub(crate) fn network(
+        &self,
+        properties: &serde_json::Map<std::string::String, serde_json::Value>,
+    ) -> std::option::Option<super::Network> {
+        let technology: std::string::String = Self::text(properties, &self.technology)?;
+        let speed_class: std::string::String =
+            Self::text(properties, &self.speed_class).unwrap_or_default();
+        let mbps: std::option::Option<std::primitive::u32> = match properties.get(&self.mbps) {
+            Some(serde_json::Value::Number(mbps)) => mbps
+                .as_f64()
+                .map(|mbps| mbps.max(0.0) as std::primitive::u32),
+            Some(serde_json::Value::String(mbps)) => {
+                mbps.trim().parse::<std::primitive::u32>().ok()
+            }
+            _ => speed_class
+                .chars()
+                .skip_while(|c| !c.is_ascii_digit())
+                .take_while(|c| c.is_ascii_digit())
+                .collect::<std::string::String>()
+                .parse::<std::primitive::u32>()
+                .ok(),
+        };
+        Some(super::Network {
+            operator: Self::text(properties, &self.operator).unwrap_or_default(),
+            technology,
+            speed_class,
+            mbps,
+        })
+    }
+}
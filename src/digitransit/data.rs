@@ -0,0 +1,5 @@
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Data {
+    pub(super) plan: super::Plan,
+}
@@ -0,0 +1,103 @@
+/// Digitransit (OpenTripPlanner) GraphQL API client for public transport journeys.
+pub(crate) struct Digitransit {
+    base_url: std::string::String,
+    headers: reqwest::header::HeaderMap,
+    limiter: std::option::Option<std::sync::Arc<tokio::sync::Mutex<crate::client::BetweenCalls>>>,
+    departure: chrono::NaiveDateTime,
+}
+
+/// Base URL for the public Digitransit HSL routing API.
+const API_URL: &std::primitive::str = "https://api.digitransit.fi/routing/v2/hsl/gtfs/v1";
+
+/// GraphQL query for the fastest itinerary between two locations.
+const PLAN_QUERY: &std::primitive::str = "query Plan($fromLat: Float!, $fromLon: Float!, $toLat: Float!, $toLon: Float!, $date: String!, $time: String!) {
+    plan(from: {lat: $fromLat, lon: $fromLon}, to: {lat: $toLat, lon: $toLon}, date: $date, time: $time, numItineraries: 1) {
+        itineraries {
+            duration
+        }
+    }
+}";
+
+static LIMITER: once_cell::sync::Lazy<
+    std::sync::Arc<tokio::sync::Mutex<crate::client::BetweenCalls>>,
+> = once_cell::sync::Lazy::new(|| {
+    std::sync::Arc::new(tokio::sync::Mutex::new(crate::client::BetweenCalls::new(
+        1000,
+    )))
+});
+
+impl Digitransit {
+    /// Create new Digitransit API client.
+    /// Public API is rate limited, self-hosted instance given with `base_url` is not.
+    ///
+    /// # Arguments
+    /// * `base_url` - Optional base URL for self-hosted OpenTripPlanner instance, for example: http://localhost:8080/otp/gtfs/v1
+    /// * `subscription_key` - Optional Digitransit subscription key: https://portal-api.digitransit.fi/
+    /// * `departure` - Departure date and time for the journeys.
+    pub(crate) fn new(
+        base_url: std::option::Option<&std::primitive::str>,
+        subscription_key: std::option::Option<&std::primitive::str>,
+        departure: chrono::NaiveDateTime,
+    ) -> std::result::Result<Self, reqwest::header::InvalidHeaderValue> {
+        let mut headers: reqwest::header::HeaderMap = reqwest::header::HeaderMap::new();
+        if let Some(subscription_key) = subscription_key {
+            let _: bool = headers.append(
+                "digitransit-subscription-key",
+                reqwest::header::HeaderValue::from_str(subscription_key)?,
+            );
+        }
+        Ok(Self {
+            base_url: base_url
+                .unwrap_or(API_URL)
+                .trim_end_matches('/')
+                .to_string(),
+            headers,
+            limiter: match base_url {
+                Some(_) => None,
+                None => Some(std::sync::Arc::clone(&LIMITER)),
+            },
+            departure,
+        })
+    }
+
+    /// Get public transport journey time in minutes between two locations.
+    ///
+    /// # Arguments
+    /// * `from` - Starting location.
+    /// * `to` - Destination location.
+    pub(crate) async fn transit_minutes(
+        &self,
+        from: longitude::Location,
+        to: longitude::Location,
+    ) -> std::result::Result<std::option::Option<std::primitive::u16>, crate::client::JSONError>
+    {
+        Ok(crate::client::Client::new(
+            // Always caching journeys, because departure date is part of the request.
+            Some("digitransit/plan"),
+            self.limiter.clone(),
+        )?
+        .post_json::<super::Response>(
+            &self.base_url,
+            serde_json::json!({
+                "query": PLAN_QUERY,
+                "variables": {
+                    "fromLat": from.latitude,
+                    "fromLon": from.longitude,
+                    "toLat": to.latitude,
+                    "toLon": to.longitude,
+                    "date": self.departure.format("%Y-%m-%d").to_string(),
+                    "time": self.departure.format("%H:%M:%S").to_string(),
+                },
+            }),
+            Some(self.headers.clone()),
+        )
+        .await?
+        .data
+        .plan
+        .itineraries
+        .first()
+        .map(|itinerary| {
+            (itinerary.duration as std::primitive::f64 / 60.0).ceil() as std::primitive::u16
+        }))
+    }
+}
@@ -0,0 +1,6 @@
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Itinerary {
+    /// Duration in seconds.
+    pub(super) duration: std::primitive::u64,
+}
@@ -0,0 +1,12 @@
+mod data;
+mod digitransit;
+mod itinerary;
+mod plan;
+mod response;
+
+pub(crate) use self::digitransit::Digitransit;
+
+use self::data::Data;
+use self::itinerary::Itinerary;
+use self::plan::Plan;
+use self::response::Response;
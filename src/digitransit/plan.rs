@@ -0,0 +1,5 @@
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Plan {
+    pub(super) itineraries: std::vec::Vec<super::Itinerary>,
+}
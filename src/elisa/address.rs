@@ -2,4 +2,24 @@
 #[serde(rename_all = "camelCase")]
 pub(super) struct Address {
     pub(super) address_id: std::primitive::u64,
+    #[serde(default)]
+    pub(super) street_name: std::option::Option<std::string::String>,
+    #[serde(default)]
+    pub(super) street_number: std::option::Option<std::string::String>,
+}
+
+impl Address {
+    /// Does the address match the searched street address?
+    ///
+    /// # Arguments
+    /// * `street_address` - Searched street address, for example: Kotitie 5
+    pub(super) fn matches(&self, street_address: &std::primitive::str) -> std::primitive::bool {
+        match (&self.street_name, &self.street_number) {
+            (Some(street_name), Some(street_number)) => {
+                format!("{} {}", street_name, street_number).to_lowercase()
+                    == street_address.to_lowercase()
+            }
+            _ => false,
+        }
+    }
 }
@@ -12,45 +12,76 @@ static LIMITER: once_cell::sync::Lazy<
 
 impl Elisa {
     /// Create a new Elisa instance.
+    /// Searches address with normalized street address candidates until one matches exactly,
+    /// falls back to the first found address and gets products only for that address.
+    ///
+    /// # Arguments
+    /// * `postal_code` - Postal code.
+    /// * `street_address` - Street address as given in the announcement.
+    /// * `cache_fixed_boardband_products` - Cache fixed broadband products?
+    ///
+    /// # Returns
+    /// None if Elisa does not know the address, so internet availability is unknown.
     pub(crate) async fn new(
         postal_code: &std::primitive::str,
         street_address: &std::primitive::str,
         cache_fixed_boardband_products: std::primitive::bool,
-    ) -> std::result::Result<Self, crate::client::JSONError> {
+    ) -> std::result::Result<std::option::Option<Self>, crate::client::JSONError> {
+        // Exact match from any candidate is better than first result of the first candidate.
+        let mut fallback: std::option::Option<super::Address> = None;
+        let mut exact: std::option::Option<super::Address> = None;
+        for candidate in super::street_address::candidates(street_address) {
+            let addresses: std::vec::Vec<super::Address> = crate::client::Client::new(
+                // We should alwaus cache the address result, because it is not likely to change.
+                Some("elisa/address/search"),
+                Some(std::sync::Arc::clone(&LIMITER)),
+            )?
+            .get_json::<std::vec::Vec<super::Address>>(
+                &format!(
+                    "https://elisa.fi/kauppa/rest/address/search/{}/{}",
+                    postal_code, candidate
+                ),
+                None,
+            )
+            .await?;
+            if let Some(address) = addresses.iter().find(|address| address.matches(&candidate)) {
+                exact = Some(address.clone());
+                break;
+            }
+            if fallback.is_none() {
+                fallback = addresses.into_iter().next();
+            }
+        }
+        let address: super::Address = match exact.or(fallback) {
+            Some(address) => address,
+            None => return Ok(None),
+        };
+
         let mut internets: std::vec::Vec<super::Internet> = std::vec::Vec::<super::Internet>::new();
-        for address in crate::client::Client::new(
-            // We should alwaus cache the address result, because it is not likely to change.
-            Some("elisa/address/search"),
+        for product in crate::client::Client::new(
+            if cache_fixed_boardband_products {
+                Some("elisa/products/fixedBroadbandProducts")
+            } else {
+                None
+            },
             Some(std::sync::Arc::clone(&LIMITER)),
         )?
-        .get_json::<std::vec::Vec<super::Address>>(&format!(
-            "https://elisa.fi/kauppa/rest/address/search/{}/{}",
-            postal_code, street_address
-        ))
-        .await?
-        {
-            for product in crate::client::Client::new(
-                if cache_fixed_boardband_products {
-                    Some("elisa/products/fixedBroadbandProducts")
-                } else {
-                    None
-                },
-                Some(std::sync::Arc::clone(&LIMITER)),
-            )?
-            .get_json::<super::Response>(&format!(
+        .get_json::<super::Response>(
+            &format!(
                 "https://elisa.fi/kauppa/rest/products/fixedBroadbandProducts/{}/{}",
                 postal_code, address.address_id
-            ))
-            .await?
-            .fbb_products
-            {
-                let internet: super::Internet = super::Internet::new(product);
-                if internet.include() {
-                    internets.push(internet);
-                }
+            ),
+            None,
+        )
+        .await?
+        .fbb_products
+        {
+            let internet: super::Internet = super::Internet::new(product);
+            if internet.include() {
+                internets.push(internet);
             }
         }
-        Ok(Self { internets })
+        Ok(Some(Self { internets }))
     }
 
     /// Get internet products.
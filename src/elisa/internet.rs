@@ -21,9 +21,19 @@ impl self::Internet {
 }
 
 impl crate::app::Internet for self::Internet {
+    /// Name of the provider.
+    fn provider(&self) -> std::string::String {
+        "Elisa".to_string()
+    }
+
     /// Name for the internet product.
     fn name(&self) -> std::string::String {
-        format!("{} ({})", self.product.name, self.product.r#type)
+        self.product.name.clone()
+    }
+
+    /// Technology of the internet product.
+    fn technology(&self) -> std::string::String {
+        self.product.r#type.clone()
     }
 
     /// Price in euros per month.
@@ -3,6 +3,7 @@ mod elisa;
 mod internet;
 mod product;
 mod response;
+mod street_address;
 
 pub(crate) use self::elisa::Elisa;
 pub(crate) use self::internet::Internet;
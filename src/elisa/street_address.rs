@@ -0,0 +1,174 @@
+/// Street name suffix abbreviations and their full forms.
+const ABBREVIATIONS: [(&std::primitive::str, &std::primitive::str); 6] = [
+    ("t.", "tie"),
+    ("k.", "katu"),
+    ("kj.", "kuja"),
+    ("p.", "polku"),
+    ("v.", "vägen"),
+    ("g.", "gatan"),
+];
+
+/// Street name suffixes that are sometimes written as separate word.
+const SUFFIXES: [&std::primitive::str; 8] = [
+    "tie", "katu", "kuja", "polku", "raitti", "vägen", "gatan", "gränd",
+];
+
+/// Value without the ASCII suffix, compared case-insensitively.
+///
+/// # Arguments
+/// * `value` - Value to strip the suffix from.
+/// * `suffix` - ASCII suffix.
+fn strip_suffix_ignore_case<'a>(
+    value: &'a std::primitive::str,
+    suffix: &std::primitive::str,
+) -> std::option::Option<&'a std::primitive::str> {
+    let start: std::primitive::usize = value.len().checked_sub(suffix.len())?;
+    // Not a char boundary if the suffix would start in the middle of non-ASCII character.
+    if !value.get(start..)?.eq_ignore_ascii_case(suffix) {
+        return None;
+    }
+    Some(&value[..start])
+}
+
+/// Street address candidates to search from Elisa in order of preference.
+/// Removes staircase and apartment parts, expands abbreviated suffixes,
+/// joins separately written suffixes and splits bilingual Finnish and Swedish names.
+///
+/// # Arguments
+/// * `street_address` - Street address, for example: Kotitie 5 A 3 / Hemvägen 5 A 3
+pub(super) fn candidates(
+    street_address: &std::primitive::str,
+) -> std::vec::Vec<std::string::String> {
+    let mut candidates: std::vec::Vec<std::string::String> =
+        std::vec::Vec::<std::string::String>::new();
+    for part in street_address.split(['/', ',']) {
+        let mut name: std::vec::Vec<std::string::String> =
+            std::vec::Vec::<std::string::String>::new();
+        let mut number: std::option::Option<std::string::String> = None;
+        for word in part.split_whitespace() {
+            if word.starts_with(|c: std::primitive::char| c.is_ascii_digit()) {
+                number = Some(word.to_string());
+                // Rest of the words are staircase and apartment.
+                break;
+            }
+            name.push(word.to_string());
+        }
+        if name.is_empty() {
+            continue;
+        }
+
+        let joined: std::string::String = name.join(" ");
+        let mut names: std::vec::Vec<std::string::String> = std::vec![joined.clone()];
+        if let Some(last) = name.last() {
+            let last_lowercase: std::string::String = last.to_lowercase();
+            for (abbreviation, suffix) in ABBREVIATIONS {
+                if let Some(stem) = strip_suffix_ignore_case(&joined, abbreviation) {
+                    names.push(format!("{}{}", stem, suffix));
+                }
+            }
+            if 1 < name.len() && SUFFIXES.contains(&last_lowercase.as_str()) {
+                names.push(format!(
+                    "{}{}",
+                    name[..name.len() - 1].join(" "),
+                    last_lowercase
+                ));
+            }
+        }
+
+        for name in names {
+            match &number {
+                Some(number) => {
+                    candidates.push(format!("{} {}", name, number));
+                    // House letter without space, for example 5a.
+                    let digits: std::string::String =
+                        number.chars().take_while(|c| c.is_ascii_digit()).collect();
+                    if digits != *number {
+                        candidates.push(format!("{} {}", name, digits));
+                    }
+                }
+                None => candidates.push(name),
+            }
+        }
+    }
+
+    let mut unique: std::vec::Vec<std::string::String> =
+        std::vec::Vec::<std::string::String>::new();
+    for candidate in candidates {
+        if !unique.contains(&candidate) {
+            unique.push(candidate);
+        }
+    }
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn removes_staircase_and_apartment() {
+        assert_eq!(
+            super::candidates("Kotitie 5 A 3"),
+            std::vec!["Kotitie 5".to_string()]
+        );
+    }
+
+    #[test]
+    fn house_letter_without_space() {
+        assert_eq!(
+            super::candidates("Kotitie 5a"),
+            std::vec!["Kotitie 5a".to_string(), "Kotitie 5".to_string()]
+        );
+    }
+
+    #[test]
+    fn expands_abbreviations() {
+        assert_eq!(
+            super::candidates("Kotit. 5"),
+            std::vec!["Kotit. 5".to_string(), "Kotitie 5".to_string()]
+        );
+        assert_eq!(
+            super::candidates("Hemv. 5"),
+            std::vec!["Hemv. 5".to_string(), "Hemvägen 5".to_string()]
+        );
+    }
+
+    #[test]
+    fn does_not_expand_abbreviation_of_non_ascii_character() {
+        // Kelvin sign is lowercase k, but longer in UTF-8.
+        assert_eq!(
+            super::candidates("Koti\u{212A}. 5"),
+            std::vec!["Koti\u{212A}. 5".to_string()]
+        );
+        assert_eq!(
+            super::candidates("KOTIK. 5"),
+            std::vec!["KOTIK. 5".to_string(), "KOTIkatu 5".to_string()]
+        );
+    }
+
+    #[test]
+    fn joins_separate_suffix() {
+        assert_eq!(
+            super::candidates("Koti tie 5"),
+            std::vec!["Koti tie 5".to_string(), "Kotitie 5".to_string()]
+        );
+    }
+
+    #[test]
+    fn splits_slash_and_comma() {
+        assert_eq!(
+            super::candidates("Kotitie 5 A 3 / Hemvägen 5 A 3"),
+            std::vec!["Kotitie 5".to_string(), "Hemvägen 5".to_string()]
+        );
+        assert_eq!(
+            super::candidates("Kotitie 5, Hemvägen 5"),
+            std::vec!["Kotitie 5".to_string(), "Hemvägen 5".to_string()]
+        );
+    }
+
+    #[test]
+    fn removes_duplicates_and_empty_parts() {
+        assert_eq!(
+            super::candidates("Kotitie 5 / Kotitie 5 / 7"),
+            std::vec!["Kotitie 5".to_string()]
+        );
+    }
+}
@@ -0,0 +1,7 @@
+/// File attached to an email.
+#[derive(Debug, Clone)]
+pub(crate) struct Attachment {
+    pub(crate) file_name: std::string::String,
+    pub(crate) content_type: &'static std::primitive::str,
+    pub(crate) content: std::vec::Vec<std::primitive::u8>,
+}
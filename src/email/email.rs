@@ -0,0 +1,222 @@
+/// Email sender using SMTP server.
+#[derive(Debug, Clone)]
+pub(crate) struct Email {
+    pub(self) host: std::string::String,
+    pub(self) port: std::primitive::u16,
+    pub(self) tls: std::string::String,
+    pub(self) credentials: std::option::Option<(std::string::String, std::string::String)>,
+    pub(self) insecure_auth: std::primitive::bool,
+    pub(self) from: std::string::String,
+    pub(self) to: std::vec::Vec<std::string::String>,
+}
+
+impl Email {
+    /// Create new email sender.
+    ///
+    /// # Arguments
+    /// * `host` - SMTP server host.
+    /// * `port` - SMTP server port.
+    /// * `tls` - TLS mode. One of: NONE, STARTTLS or TLS
+    /// * `credentials` - Optional username and password for SMTP server.
+    /// * `insecure_auth` - Allow sending credentials without TLS.
+    /// * `from` - Sender email address.
+    /// * `to` - Recipient email addresses.
+    pub(crate) fn new(
+        host: &std::primitive::str,
+        port: std::primitive::u16,
+        tls: &std::primitive::str,
+        credentials: std::option::Option<(std::string::String, std::string::String)>,
+        insecure_auth: std::primitive::bool,
+        from: &std::primitive::str,
+        to: std::vec::Vec<std::string::String>,
+    ) -> Self {
+        Self {
+            host: host.to_string(),
+            port,
+            tls: tls.to_string(),
+            credentials,
+            insecure_auth,
+            from: from.to_string(),
+            to,
+        }
+    }
+
+    /// Send HTML email with attachments to every recipient.
+    ///
+    /// # Arguments
+    /// * `subject` - Subject of the email.
+    /// * `html` - HTML body of the email.
+    /// * `attachments` - Files to attach.
+    pub(crate) async fn send(
+        &self,
+        subject: &std::primitive::str,
+        html: &std::primitive::str,
+        attachments: &[super::Attachment],
+    ) -> std::result::Result<(), super::Error> {
+        let message: lettre::Message = self.message(subject, html, attachments)?;
+        let _: lettre::transport::smtp::response::Response =
+            lettre::AsyncTransport::send(&self.transport()?, message).await?;
+        Ok(())
+    }
+
+    /// SMTP transport for the server.
+    pub(self) fn transport(
+        &self,
+    ) -> std::result::Result<lettre::AsyncSmtpTransport<lettre::Tokio1Executor>, super::Error> {
+        let tls: lettre::transport::smtp::client::Tls = match self.tls.as_str() {
+            "TLS" => lettre::transport::smtp::client::Tls::Wrapper(
+                lettre::transport::smtp::client::TlsParameters::new(self.host.clone())?,
+            ),
+            "STARTTLS" => lettre::transport::smtp::client::Tls::Required(
+                lettre::transport::smtp::client::TlsParameters::new(self.host.clone())?,
+            ),
+            _ => lettre::transport::smtp::client::Tls::None,
+        };
+        let mut builder: lettre::transport::smtp::AsyncSmtpTransportBuilder =
+            lettre::AsyncSmtpTransport::<lettre::Tokio1Executor>::builder_dangerous(&self.host)
+                .port(self.port)
+                .tls(tls)
+                .timeout(Some(SMTP_TIMEOUT));
+        if let Some((username, password)) = &self.credentials {
+            // Credentials would be sent in plain text.
+            if self.tls == "NONE" && !self.insecure_auth {
+                return Err(super::Error::InsecureAuthentication);
+            }
+            builder =
+                builder.credentials(lettre::transport::smtp::authentication::Credentials::new(
+                    username.clone(),
+                    password.clone(),
+                ));
+        }
+        Ok(builder.build())
+    }
+
+    /// Build message with HTML body and attachments.
+    ///
+    /// # Arguments
+    /// * `subject` - Subject of the email.
+    /// * `html` - HTML body of the email.
+    /// * `attachments` - Files to attach.
+    pub(self) fn message(
+        &self,
+        subject: &std::primitive::str,
+        html: &std::primitive::str,
+        attachments: &[super::Attachment],
+    ) -> std::result::Result<lettre::Message, super::Error> {
+        let mut builder: lettre::message::MessageBuilder = lettre::Message::builder()
+            .from(self.from.parse::<lettre::message::Mailbox>()?)
+            .subject(subject);
+        for to in &self.to {
+            builder = builder.to(to.parse::<lettre::message::Mailbox>()?);
+        }
+        let mut multipart: lettre::message::MultiPart = lettre::message::MultiPart::mixed()
+            .singlepart(lettre::message::SinglePart::html(html.to_string()));
+        for attachment in attachments {
+            multipart = multipart.singlepart(
+                lettre::message::Attachment::new(attachment.file_name.clone()).body(
+                    attachment.content.clone(),
+                    lettre::message::header::ContentType::parse(attachment.content_type)?,
+                ),
+            );
+        }
+        Ok(builder.multipart(multipart)?)
+    }
+}
+
+impl crate::app::Notifier for Email {
+    /// Send email with alerts and table of houses, if houses were found.
+    fn notify<'a>(
+        &'a self,
+        notification: &'a crate::app::Notification,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<Output = std::result::Result<(), crate::app::Error>>
+                + Send
+                + 'a,
+        >,
+    > {
+        std::boxed::Box::pin(async move {
+            if notification.houses.is_empty() {
+                return Ok(());
+            }
+            let mut html: std::string::String = std::string::String::new();
+            for alert in &notification.alerts {
+                html.push_str(&format!("<p>{}</p>\n", crate::html::escape(&alert.message)));
+            }
+            html.push_str(&notification.table_html);
+            self.send(
+                &format!("Found {} houses", notification.houses.len()),
+                &html,
+                &match &notification.csv_path {
+                    Some(csv_path) => std::vec![super::Attachment {
+                        file_name: "results.csv".to_string(),
+                        content_type: "text/csv",
+                        content: std::fs::read(csv_path)?,
+                    }],
+                    None => std::vec::Vec::<super::Attachment>::new(),
+                },
+            )
+            .await?;
+            eprintln!("Sent email to: {}", self.to.join(", "));
+            Ok(())
+        })
+    }
+
+    /// Send plain text message as email.
+    fn send_message<'a>(
+        &'a self,
+        message: &'a std::primitive::str,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<Output = std::result::Result<(), crate::app::Error>>
+                + Send
+                + 'a,
+        >,
+    > {
+        std::boxed::Box::pin(async move {
+            Ok(self
+                .send(
+                    "House finder",
+                    &format!("<pre>{}</pre>", crate::html::escape(message)),
+                    &[],
+                )
+                .await?)
+        })
+    }
+}
+
+/// Timeout for connecting to and every command with the SMTP server.
+const SMTP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+#[cfg(test)]
+mod tests {
+    fn email(tls: &std::primitive::str, insecure_auth: std::primitive::bool) -> super::Email {
+        super::Email::new(
+            "localhost",
+            25,
+            tls,
+            Some(("user".to_string(), "password".to_string())),
+            insecure_auth,
+            "house@localhost",
+            std::vec!["me@localhost".to_string()],
+        )
+    }
+
+    #[test]
+    fn refuses_credentials_without_tls() {
+        assert!(matches!(
+            email("NONE", false).transport(),
+            Err(crate::email::Error::InsecureAuthentication)
+        ));
+    }
+
+    #[test]
+    fn allows_credentials_without_tls_when_asked() {
+        assert!(email("NONE", true).transport().is_ok());
+    }
+
+    #[test]
+    fn allows_credentials_with_tls() {
+        assert!(email("STARTTLS", false).transport().is_ok());
+    }
+}
@@ -0,0 +1,43 @@
+#[derive(Debug)]
+pub(crate) enum Error {
+    Address(lettre::address::AddressError),
+    ContentType(lettre::message::header::ContentTypeErr),
+    Message(lettre::error::Error),
+    Transport(lettre::transport::smtp::Error),
+    /// Credentials were given without TLS and without allowing it.
+    InsecureAuthentication,
+}
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Address(e) => write!(f, "Email address error:\n{}", e),
+            Error::ContentType(e) => write!(f, "Content type error:\n{}", e),
+            Error::Message(e) => write!(f, "Email message error:\n{}", e),
+            Error::Transport(e) => write!(f, "SMTP error:\n{}", e),
+            Error::InsecureAuthentication => write!(
+                f,
+                "Refusing to send SMTP credentials without TLS, use --smtp-insecure-auth to allow it."
+            ),
+        }
+    }
+}
+impl From<lettre::address::AddressError> for Error {
+    fn from(err: lettre::address::AddressError) -> Self {
+        Error::Address(err)
+    }
+}
+impl From<lettre::message::header::ContentTypeErr> for Error {
+    fn from(err: lettre::message::header::ContentTypeErr) -> Self {
+        Error::ContentType(err)
+    }
+}
+impl From<lettre::error::Error> for Error {
+    fn from(err: lettre::error::Error) -> Self {
+        Error::Message(err)
+    }
+}
+impl From<lettre::transport::smtp::Error> for Error {
+    fn from(err: lettre::transport::smtp::Error) -> Self {
+        Error::Transport(err)
+    }
+}
@@ -0,0 +1,7 @@
+mod attachment;
+mod email;
+mod error;
+
+pub(crate) use self::attachment::Attachment;
+pub(crate) use self::email::Email;
+pub(crate) use self::error::Error;
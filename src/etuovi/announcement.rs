@@ -43,6 +43,21 @@ impl Announcement {
         self.html = Some(html.clone());
         return Ok(html);
     }
+
+    /// Get first capture group from HTML, if it matches.
+    ///
+    /// # Arguments
+    /// * `pattern` - Regex pattern with one capture group.
+    pub(self) async fn capture(
+        &mut self,
+        pattern: &std::primitive::str,
+    ) -> std::result::Result<std::option::Option<std::string::String>, super::RegexError> {
+        let html: std::string::String = self.html().await?;
+        Ok(regex::Regex::new(pattern)?
+            .captures(&html)
+            .and_then(|captures| captures.get(1))
+            .map(|capture| capture.as_str().to_string()))
+    }
 }
 
 impl crate::app::Announcement for Announcement {
@@ -105,6 +120,16 @@ impl crate::app::Announcement for Announcement {
             .to_string())
     }
 
+    /// Municipality from the last part of the second address line.
+    fn municipality(&self) -> std::option::Option<std::string::String> {
+        self.raw
+            .address_line2
+            .as_ref()
+            .and_then(|address_line2| address_line2.rsplit(',').next())
+            .map(|municipality| municipality.trim().to_string())
+            .filter(|municipality| !municipality.is_empty())
+    }
+
     /// Construction year.
     fn year(&self) -> std::option::Option<std::primitive::u16> {
         self.raw.construction_finished_year
@@ -149,4 +174,26 @@ impl crate::app::Announcement for Announcement {
             .as_str()
             .to_string())
     }
+
+    /// Heating system.
+    async fn heating(
+        &mut self,
+    ) -> std::result::Result<std::option::Option<std::string::String>, super::RegexError> {
+        self.capture(r#""heatingSystems":\["([A-Z_]+)""#).await
+    }
+
+    /// Energy class.
+    async fn energy_class(
+        &mut self,
+    ) -> std::result::Result<std::option::Option<std::string::String>, super::RegexError> {
+        self.capture(r#""energyClass":"([A-G][0-9]*)""#).await
+    }
+
+    /// URL for the main photo from the page preview image.
+    async fn photo_url(
+        &mut self,
+    ) -> std::result::Result<std::option::Option<std::string::String>, super::RegexError> {
+        self.capture(r#"<meta property="og:image" content="([^"]+)""#)
+            .await
+    }
 }
@@ -3,6 +3,7 @@
 pub(crate) struct AnnouncementRaw {
     pub(super) friendly_id: std::string::String,
     pub(super) address_line1: std::string::String,
+    pub(super) address_line2: std::option::Option<std::string::String>,
     pub(super) latitude: std::option::Option<std::primitive::f64>,
     pub(super) longitude: std::option::Option<std::primitive::f64>,
     pub(super) construction_finished_year: std::option::Option<std::primitive::u16>,
@@ -0,0 +1,140 @@
+/// Filter expression evaluated against fields of a house.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Expression {
+    Or(std::boxed::Box<Expression>, std::boxed::Box<Expression>),
+    And(std::boxed::Box<Expression>, std::boxed::Box<Expression>),
+    Not(std::boxed::Box<Expression>),
+    Comparison {
+        left: super::Operand,
+        operator: super::Operator,
+        right: super::Operand,
+    },
+}
+
+impl Expression {
+    /// Parse filter expression, for example: year >= 1990 && (heating == "geothermal" || price_per_m2 < 2500)
+    ///
+    /// # Arguments
+    /// * `expression` - Filter expression.
+    pub(crate) fn parse(
+        expression: &std::primitive::str,
+    ) -> std::result::Result<Self, super::ParseError> {
+        super::Parser::new(expression)?.parse()
+    }
+
+    /// Fields used in the expression.
+    pub(crate) fn fields(&self) -> std::vec::Vec<super::Field> {
+        match self {
+            Expression::Or(left, right) | Expression::And(left, right) => {
+                let mut fields: std::vec::Vec<super::Field> = left.fields();
+                for field in right.fields() {
+                    if !fields.contains(&field) {
+                        fields.push(field);
+                    }
+                }
+                fields
+            }
+            Expression::Not(expression) => expression.fields(),
+            Expression::Comparison { left, right, .. } => {
+                let mut fields: std::vec::Vec<super::Field> = std::vec::Vec::<super::Field>::new();
+                for operand in [left, right] {
+                    if let super::Operand::Field(field) = operand {
+                        if !fields.contains(field) {
+                            fields.push(*field);
+                        }
+                    }
+                }
+                fields
+            }
+        }
+    }
+
+    /// Evaluate expression against values of the fields.
+    /// Comparisons with unknown values are false.
+    ///
+    /// # Arguments
+    /// * `values` - Values of the fields.
+    pub(crate) fn evaluate(
+        &self,
+        values: &std::collections::HashMap<super::Field, super::Value>,
+    ) -> std::primitive::bool {
+        match self {
+            Expression::Or(left, right) => left.evaluate(values) || right.evaluate(values),
+            Expression::And(left, right) => left.evaluate(values) && right.evaluate(values),
+            Expression::Not(expression) => !expression.evaluate(values),
+            Expression::Comparison {
+                left,
+                operator,
+                right,
+            } => operator.compare(&left.value(values), &right.value(values)),
+        }
+    }
+}
+
+impl std::fmt::Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expression::Or(left, right) => write!(f, "({} || {})", left, right),
+            Expression::And(left, right) => write!(f, "({} && {})", left, right),
+            Expression::Not(expression) => write!(f, "!{}", expression),
+            Expression::Comparison {
+                left,
+                operator,
+                right,
+            } => write!(f, "{} {} {}", left, operator.symbol(), right),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    /// Evaluate expression with values of the fields.
+    ///
+    /// # Arguments
+    /// * `expression` - Filter expression.
+    /// * `values` - Values of the fields.
+    fn evaluate(
+        expression: &std::primitive::str,
+        values: &[(super::super::Field, super::super::Value)],
+    ) -> std::primitive::bool {
+        super::Expression::parse(expression)
+            .unwrap()
+            .evaluate(&values.iter().cloned().collect())
+    }
+
+    #[test]
+    fn evaluates_comparisons() {
+        let values: [(super::super::Field, super::super::Value); 2] = [
+            (
+                super::super::Field::Price,
+                super::super::Value::Number(100.0),
+            ),
+            (
+                super::super::Field::Heating,
+                super::super::Value::Text("Geothermal heat".to_string()),
+            ),
+        ];
+        assert!(evaluate(
+            "price == 100 && price <= 100 && price >= 100",
+            &values
+        ));
+        assert!(evaluate("price > -1 && price != 99", &values));
+        assert!(!evaluate("price < 100 || price > 100", &values));
+        assert!(evaluate(r#"heating ~ "GEOTHERMAL""#, &values));
+        assert!(evaluate(r#"heating == "geothermal heat""#, &values));
+        assert!(evaluate(r#"price < 50 || heating != "oil""#, &values));
+    }
+
+    #[test]
+    fn comparisons_with_unknown_values_are_false() {
+        let values: [(super::super::Field, super::super::Value); 1] =
+            [(super::super::Field::Price, super::super::Value::Unknown)];
+        assert!(!evaluate("price < 1", &values));
+        assert!(!evaluate("price >= 1", &values));
+        assert!(!evaluate("price != 1", &values));
+        assert!(!evaluate(r#"heating == "oil""#, &values));
+        // Negation of false comparison is true even if the value is unknown.
+        assert!(evaluate("!(price < 1)", &values));
+        assert!(evaluate(r#"!(heating == "oil")"#, &values));
+    }
+}
@@ -0,0 +1,87 @@
+/// House field that can be used in filter expressions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Field {
+    Price,
+    PricePerSquareMeter,
+    Area,
+    TotalArea,
+    Year,
+    Floors,
+    DistanceKm,
+    BikingKm,
+    TransitMinutes,
+    Mbps,
+    MonthlyCost,
+    OneOffCost,
+    Score,
+    Heating,
+    EnergyClass,
+    Municipality,
+}
+
+/// Type of the field value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Kind {
+    Number,
+    Text,
+}
+
+impl Field {
+    /// All fields in the order they are documented.
+    pub(crate) const ALL: [Field; 16] = [
+        Field::Price,
+        Field::PricePerSquareMeter,
+        Field::Area,
+        Field::TotalArea,
+        Field::Year,
+        Field::Floors,
+        Field::DistanceKm,
+        Field::BikingKm,
+        Field::TransitMinutes,
+        Field::Mbps,
+        Field::MonthlyCost,
+        Field::OneOffCost,
+        Field::Score,
+        Field::Heating,
+        Field::EnergyClass,
+        Field::Municipality,
+    ];
+
+    /// Name of the field in filter expressions.
+    pub(crate) fn name(&self) -> &'static std::primitive::str {
+        match self {
+            Field::Price => "price",
+            Field::PricePerSquareMeter => "price_per_m2",
+            Field::Area => "area",
+            Field::TotalArea => "total_area",
+            Field::Year => "year",
+            Field::Floors => "floors",
+            Field::DistanceKm => "distance_km",
+            Field::BikingKm => "biking_km",
+            Field::TransitMinutes => "transit_minutes",
+            Field::Mbps => "mbps",
+            Field::MonthlyCost => "monthly_cost",
+            Field::OneOffCost => "one_off_cost",
+            Field::Score => "score",
+            Field::Heating => "heating",
+            Field::EnergyClass => "energy_class",
+            Field::Municipality => "municipality",
+        }
+    }
+
+    /// Field from it's name in filter expressions.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the field.
+    pub(crate) fn from_name(name: &std::primitive::str) -> std::option::Option<Self> {
+        Field::ALL.into_iter().find(|field| field.name() == name)
+    }
+
+    /// Type of the field value.
+    pub(crate) fn kind(&self) -> Kind {
+        match self {
+            Field::Heating | Field::EnergyClass | Field::Municipality => Kind::Text,
+            _ => Kind::Number,
+        }
+    }
+}
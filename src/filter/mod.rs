@@ -0,0 +1,19 @@
+mod expression;
+mod field;
+mod operand;
+mod operator;
+mod parse_error;
+mod parser;
+mod token;
+mod value;
+
+pub(crate) use self::expression::Expression;
+pub(crate) use self::field::Field;
+pub(crate) use self::value::Value;
+
+use self::field::Kind;
+use self::operand::Operand;
+use self::operator::Operator;
+use self::parse_error::ParseError;
+use self::parser::Parser;
+use self::token::Token;
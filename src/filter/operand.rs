@@ -0,0 +1,39 @@
+/// Operand of a comparison in filter expressions.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Operand {
+    Field(super::Field),
+    Literal(super::Value),
+}
+
+impl Operand {
+    /// Value of the operand.
+    ///
+    /// # Arguments
+    /// * `values` - Values of the fields.
+    pub(super) fn value(
+        &self,
+        values: &std::collections::HashMap<super::Field, super::Value>,
+    ) -> super::Value {
+        match self {
+            Operand::Field(field) => values.get(field).cloned().unwrap_or(super::Value::Unknown),
+            Operand::Literal(value) => value.clone(),
+        }
+    }
+
+    /// Type of the operand value.
+    pub(super) fn kind(&self) -> std::option::Option<super::Kind> {
+        match self {
+            Operand::Field(field) => Some(field.kind()),
+            Operand::Literal(value) => value.kind(),
+        }
+    }
+}
+
+impl std::fmt::Display for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operand::Field(field) => write!(f, "{}", field.name()),
+            Operand::Literal(value) => write!(f, "{}", value),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+/// Comparison operator in filter expressions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operator {
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    /// Text contains the other text.
+    Contains,
+}
+
+impl Operator {
+    /// Compare two values, false if either of them is unknown.
+    /// Texts are compared case insensitively.
+    ///
+    /// # Arguments
+    /// * `left` - Left value.
+    /// * `right` - Right value.
+    pub(super) fn compare(
+        &self,
+        left: &super::Value,
+        right: &super::Value,
+    ) -> std::primitive::bool {
+        match (left, right) {
+            (super::Value::Number(left), super::Value::Number(right)) => match self {
+                Operator::Equal => left == right,
+                Operator::NotEqual => left != right,
+                Operator::Less => left < right,
+                Operator::LessEqual => left <= right,
+                Operator::Greater => left > right,
+                Operator::GreaterEqual => left >= right,
+                Operator::Contains => false,
+            },
+            (super::Value::Text(left), super::Value::Text(right)) => {
+                let left: std::string::String = left.to_lowercase();
+                let right: std::string::String = right.to_lowercase();
+                match self {
+                    Operator::Equal => left == right,
+                    Operator::NotEqual => left != right,
+                    Operator::Contains => left.contains(&right),
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Symbol of the operator in filter expressions.
+    pub(super) fn symbol(&self) -> &'static std::primitive::str {
+        match self {
+            Operator::Equal => "==",
+            Operator::NotEqual => "!=",
+            Operator::Less => "<",
+            Operator::LessEqual => "<=",
+            Operator::Greater => ">",
+            Operator::GreaterEqual => ">=",
+            Operator::Contains => "~",
+        }
+    }
+
+    /// Can operator compare values of this type?
+    ///
+    /// # Arguments
+    /// * `kind` - Type of the values.
+    pub(super) fn supports(&self, kind: super::Kind) -> std::primitive::bool {
+        match self {
+            Operator::Equal | Operator::NotEqual => true,
+            Operator::Less | Operator::LessEqual | Operator::Greater | Operator::GreaterEqual => {
+                kind == super::Kind::Number
+            }
+            Operator::Contains => kind == super::Kind::Text,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+/// Error in filter expression.
+#[derive(Debug, Clone)]
+pub(crate) struct ParseError {
+    pub(super) expression: std::string::String,
+    pub(super) column: std::primitive::usize,
+    pub(super) message: std::string::String,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at column {}:\n{}\n{}^",
+            self.message,
+            self.column + 1,
+            self.expression,
+            " ".repeat(self.column)
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
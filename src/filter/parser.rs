@@ -0,0 +1,292 @@
+/// Recursive descent parser for filter expressions.
+///
+/// Grammar:
+/// or         = and ( "||" and )*
+/// and        = unary ( "&&" unary )*
+/// unary      = "!" unary | primary
+/// primary    = "(" or ")" | comparison
+/// comparison = operand operator operand
+/// operand    = field | number | text
+pub(super) struct Parser {
+    pub(self) expression: std::string::String,
+    pub(self) tokens: std::vec::Vec<(std::primitive::usize, super::Token)>,
+    pub(self) position: std::primitive::usize,
+}
+
+impl Parser {
+    /// Create new parser for the expression.
+    ///
+    /// # Arguments
+    /// * `expression` - Filter expression.
+    pub(super) fn new(
+        expression: &std::primitive::str,
+    ) -> std::result::Result<Self, super::ParseError> {
+        Ok(Self {
+            expression: expression.to_string(),
+            tokens: super::Token::tokenize(expression)?,
+            position: 0,
+        })
+    }
+
+    /// Parse the whole expression.
+    pub(super) fn parse(mut self) -> std::result::Result<super::Expression, super::ParseError> {
+        let expression: super::Expression = self.or()?;
+        let (column, token) = self.peek();
+        if token != super::Token::End {
+            return Err(self.error(
+                column,
+                format!("Expected && or || but got {}", token.describe()),
+            ));
+        }
+        Ok(expression)
+    }
+
+    /// Parse error at column.
+    ///
+    /// # Arguments
+    /// * `column` - Column of the error.
+    /// * `message` - Error message.
+    pub(self) fn error(
+        &self,
+        column: std::primitive::usize,
+        message: std::string::String,
+    ) -> super::ParseError {
+        super::ParseError {
+            expression: self.expression.clone(),
+            column,
+            message,
+        }
+    }
+
+    /// Current token and it's column.
+    pub(self) fn peek(&self) -> (std::primitive::usize, super::Token) {
+        self.tokens[self.position.min(self.tokens.len() - 1)].clone()
+    }
+
+    /// Take current token and move to the next one.
+    pub(self) fn next(&mut self) -> (std::primitive::usize, super::Token) {
+        let token: (std::primitive::usize, super::Token) = self.peek();
+        self.position += 1;
+        token
+    }
+
+    /// Parse or expression.
+    pub(self) fn or(&mut self) -> std::result::Result<super::Expression, super::ParseError> {
+        let mut expression: super::Expression = self.and()?;
+        while self.peek().1 == super::Token::Or {
+            let _: (std::primitive::usize, super::Token) = self.next();
+            expression = super::Expression::Or(
+                std::boxed::Box::new(expression),
+                std::boxed::Box::new(self.and()?),
+            );
+        }
+        Ok(expression)
+    }
+
+    /// Parse and expression.
+    pub(self) fn and(&mut self) -> std::result::Result<super::Expression, super::ParseError> {
+        let mut expression: super::Expression = self.unary()?;
+        while self.peek().1 == super::Token::And {
+            let _: (std::primitive::usize, super::Token) = self.next();
+            expression = super::Expression::And(
+                std::boxed::Box::new(expression),
+                std::boxed::Box::new(self.unary()?),
+            );
+        }
+        Ok(expression)
+    }
+
+    /// Parse negation.
+    pub(self) fn unary(&mut self) -> std::result::Result<super::Expression, super::ParseError> {
+        if self.peek().1 == super::Token::Not {
+            let _: (std::primitive::usize, super::Token) = self.next();
+            return Ok(super::Expression::Not(std::boxed::Box::new(self.unary()?)));
+        }
+        self.primary()
+    }
+
+    /// Parse parenthesized expression or comparison.
+    pub(self) fn primary(&mut self) -> std::result::Result<super::Expression, super::ParseError> {
+        if self.peek().1 == super::Token::LeftParenthesis {
+            let _: (std::primitive::usize, super::Token) = self.next();
+            let expression: super::Expression = self.or()?;
+            let (column, token) = self.next();
+            if token != super::Token::RightParenthesis {
+                return Err(self.error(column, format!("Expected ) but got {}", token.describe())));
+            }
+            return Ok(expression);
+        }
+        self.comparison()
+    }
+
+    /// Parse comparison and check that operand types match.
+    pub(self) fn comparison(
+        &mut self,
+    ) -> std::result::Result<super::Expression, super::ParseError> {
+        let left_column: std::primitive::usize = self.peek().0;
+        let left: super::Operand = self.operand()?;
+        let (operator_column, token) = self.next();
+        let operator: super::Operator = match token {
+            super::Token::Operator(operator) => operator,
+            token => {
+                return Err(self.error(
+                    operator_column,
+                    format!(
+                        "Expected comparison operator ==, !=, <, <=, >, >= or ~ but got {}",
+                        token.describe()
+                    ),
+                ))
+            }
+        };
+        let right: super::Operand = self.operand()?;
+        let kind: super::Kind = match (left.kind(), right.kind()) {
+            (Some(left_kind), Some(right_kind)) if left_kind != right_kind => {
+                return Err(self.error(
+                    left_column,
+                    format!("Cannot compare {:?} with {:?}", left_kind, right_kind),
+                ))
+            }
+            (Some(kind), _) | (_, Some(kind)) => kind,
+            (None, None) => super::Kind::Number,
+        };
+        if !operator.supports(kind) {
+            return Err(self.error(
+                operator_column,
+                format!(
+                    "Operator {} cannot compare {:?} values",
+                    operator.symbol(),
+                    kind
+                ),
+            ));
+        }
+        Ok(super::Expression::Comparison {
+            left,
+            operator,
+            right,
+        })
+    }
+
+    /// Parse field, number or text.
+    pub(self) fn operand(&mut self) -> std::result::Result<super::Operand, super::ParseError> {
+        let (column, token) = self.next();
+        match token {
+            super::Token::Number(number) => {
+                Ok(super::Operand::Literal(super::Value::Number(number)))
+            }
+            super::Token::Text(text) => Ok(super::Operand::Literal(super::Value::Text(text))),
+            super::Token::Identifier(identifier) => match super::Field::from_name(&identifier) {
+                Some(field) => Ok(super::Operand::Field(field)),
+                None => Err(self.error(
+                    column,
+                    format!(
+                        "Unknown field {}, expected one of: {}",
+                        identifier,
+                        super::Field::ALL
+                            .iter()
+                            .map(|field| field.name())
+                            .collect::<std::vec::Vec<&std::primitive::str>>()
+                            .join(", ")
+                    ),
+                )),
+            },
+            token => Err(self.error(
+                column,
+                format!(
+                    "Expected field, number or text but got {}",
+                    token.describe()
+                ),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn and_binds_tighter_than_or() {
+        assert_eq!(
+            super::super::Expression::parse("year > 1 || year < 2 && price > 3")
+                .unwrap()
+                .to_string(),
+            "(year > 1 || (year < 2 && price > 3))"
+        );
+        assert_eq!(
+            super::super::Expression::parse("year > 1 && year < 2 || price > 3")
+                .unwrap()
+                .to_string(),
+            "((year > 1 && year < 2) || price > 3)"
+        );
+    }
+
+    #[test]
+    fn not_binds_tighter_than_and() {
+        assert_eq!(
+            super::super::Expression::parse("!year > 1 && price > 3")
+                .unwrap()
+                .to_string(),
+            "(!year > 1 && price > 3)"
+        );
+    }
+
+    #[test]
+    fn parentheses_group() {
+        assert_eq!(
+            super::super::Expression::parse("(year > 1 || year < 2) && !(price > 3)")
+                .unwrap()
+                .to_string(),
+            "((year > 1 || year < 2) && !price > 3)"
+        );
+    }
+
+    #[test]
+    fn parses_negative_numbers() {
+        assert_eq!(
+            super::super::Expression::parse("score > -1")
+                .unwrap()
+                .to_string(),
+            "score > -1"
+        );
+    }
+
+    #[test]
+    fn reports_errors_with_columns() {
+        for (expression, column, message) in [
+            ("year > 1 year", 9, "Expected && or || but got field year"),
+            ("(year > 1", 9, "Expected ) but got end of expression"),
+            (
+                "year 1",
+                5,
+                "Expected comparison operator ==, !=, <, <=, >, >= or ~ but got number 1",
+            ),
+            ("heating == 1", 0, "Cannot compare Text with Number"),
+            ("1 == heating", 0, "Cannot compare Number with Text"),
+            (
+                "heating < \"oil\"",
+                8,
+                "Operator < cannot compare Text values",
+            ),
+            ("year ~ 1", 5, "Operator ~ cannot compare Number values"),
+            (
+                "year > && 1",
+                7,
+                "Expected field, number or text but got &&",
+            ),
+            (
+                "year >",
+                6,
+                "Expected field, number or text but got end of expression",
+            ),
+        ] {
+            let error: super::super::ParseError =
+                super::super::Expression::parse(expression).unwrap_err();
+            assert_eq!(error.column, column, "{}", expression);
+            assert_eq!(error.message, message, "{}", expression);
+        }
+        let error: super::super::ParseError =
+            super::super::Expression::parse("prise < 1").unwrap_err();
+        assert_eq!(error.column, 0);
+        assert!(error
+            .message
+            .starts_with("Unknown field prise, expected one of: price, price_per_m2,"));
+    }
+}
@@ -0,0 +1,230 @@
+/// Token in filter expressions.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Token {
+    Number(std::primitive::f64),
+    Text(std::string::String),
+    Identifier(std::string::String),
+    Operator(super::Operator),
+    And,
+    Or,
+    Not,
+    LeftParenthesis,
+    RightParenthesis,
+    End,
+}
+
+impl Token {
+    /// Split expression into tokens with their starting columns.
+    /// Last token is always end of the expression.
+    ///
+    /// # Arguments
+    /// * `expression` - Filter expression.
+    pub(super) fn tokenize(
+        expression: &std::primitive::str,
+    ) -> std::result::Result<std::vec::Vec<(std::primitive::usize, Self)>, super::ParseError> {
+        let chars: std::vec::Vec<std::primitive::char> = expression.chars().collect();
+        let error =
+            |column: std::primitive::usize, message: std::string::String| super::ParseError {
+                expression: expression.to_string(),
+                column,
+                message,
+            };
+        let mut tokens: std::vec::Vec<(std::primitive::usize, Self)> =
+            std::vec::Vec::<(std::primitive::usize, Self)>::new();
+        let mut column: std::primitive::usize = 0;
+        while column < chars.len() {
+            let start: std::primitive::usize = column;
+            let current: std::primitive::char = chars[column];
+            let next: std::option::Option<std::primitive::char> = chars.get(column + 1).copied();
+            let token: Self = match current {
+                ' ' | '\t' | '\n' | '\r' => {
+                    column += 1;
+                    continue;
+                }
+                '(' => Token::LeftParenthesis,
+                ')' => Token::RightParenthesis,
+                '&' if next == Some('&') => Token::And,
+                '|' if next == Some('|') => Token::Or,
+                '=' if next == Some('=') => Token::Operator(super::Operator::Equal),
+                '!' if next == Some('=') => Token::Operator(super::Operator::NotEqual),
+                '<' if next == Some('=') => Token::Operator(super::Operator::LessEqual),
+                '>' if next == Some('=') => Token::Operator(super::Operator::GreaterEqual),
+                '!' => Token::Not,
+                '<' => Token::Operator(super::Operator::Less),
+                '>' => Token::Operator(super::Operator::Greater),
+                '~' => Token::Operator(super::Operator::Contains),
+                '"' => {
+                    column += 1;
+                    let mut text: std::string::String = std::string::String::new();
+                    loop {
+                        match chars.get(column) {
+                            Some('"') => break,
+                            Some('\\') if chars.get(column + 1).is_some() => {
+                                text.push(chars[column + 1]);
+                                column += 2;
+                            }
+                            Some(char) => {
+                                text.push(*char);
+                                column += 1;
+                            }
+                            None => {
+                                return Err(error(start, "Unterminated text".to_string()));
+                            }
+                        }
+                    }
+                    Token::Text(text)
+                }
+                '0'..='9' | '.' | '-'
+                    if current != '-' || matches!(next, Some('0'..='9' | '.')) =>
+                {
+                    // Leading minus of negative number, there is no subtraction.
+                    if current == '-' {
+                        column += 1;
+                    }
+                    while column < chars.len()
+                        && (chars[column].is_ascii_digit() || chars[column] == '.')
+                    {
+                        column += 1;
+                    }
+                    let number: std::string::String = chars[start..column].iter().collect();
+                    tokens.push((
+                        start,
+                        Token::Number(
+                            number
+                                .parse::<std::primitive::f64>()
+                                .map_err(|_| error(start, format!("Invalid number {}", number)))?,
+                        ),
+                    ));
+                    continue;
+                }
+                char if char.is_ascii_alphabetic() || char == '_' => {
+                    while column < chars.len()
+                        && (chars[column].is_ascii_alphanumeric() || chars[column] == '_')
+                    {
+                        column += 1;
+                    }
+                    tokens.push((
+                        start,
+                        Token::Identifier(chars[start..column].iter().collect()),
+                    ));
+                    continue;
+                }
+                char => return Err(error(start, format!("Unexpected character {}", char))),
+            };
+            column += match token {
+                Token::And
+                | Token::Or
+                | Token::Operator(super::Operator::Equal)
+                | Token::Operator(super::Operator::NotEqual)
+                | Token::Operator(super::Operator::LessEqual)
+                | Token::Operator(super::Operator::GreaterEqual) => 2,
+                _ => 1,
+            };
+            tokens.push((start, token));
+        }
+        tokens.push((chars.len(), Token::End));
+        Ok(tokens)
+    }
+
+    /// Description of the token for parse errors.
+    pub(super) fn describe(&self) -> std::string::String {
+        match self {
+            Token::Number(number) => format!("number {}", number),
+            Token::Text(text) => format!("text \"{}\"", text),
+            Token::Identifier(identifier) => format!("field {}", identifier),
+            Token::Operator(operator) => format!("operator {}", operator.symbol()),
+            Token::And => "&&".to_string(),
+            Token::Or => "||".to_string(),
+            Token::Not => "!".to_string(),
+            Token::LeftParenthesis => "(".to_string(),
+            Token::RightParenthesis => ")".to_string(),
+            Token::End => "end of expression".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    /// Tokens without columns.
+    ///
+    /// # Arguments
+    /// * `expression` - Filter expression.
+    fn tokens(expression: &std::primitive::str) -> std::vec::Vec<super::Token> {
+        super::Token::tokenize(expression)
+            .unwrap()
+            .into_iter()
+            .map(|(_, token)| token)
+            .collect()
+    }
+
+    #[test]
+    fn tokenizes_operators_and_columns() {
+        assert_eq!(
+            super::Token::tokenize("year>=1990&&!(a<=1)").unwrap(),
+            std::vec![
+                (0, super::Token::Identifier("year".to_string())),
+                (
+                    4,
+                    super::Token::Operator(super::super::Operator::GreaterEqual)
+                ),
+                (6, super::Token::Number(1990.0)),
+                (10, super::Token::And),
+                (12, super::Token::Not),
+                (13, super::Token::LeftParenthesis),
+                (14, super::Token::Identifier("a".to_string())),
+                (
+                    15,
+                    super::Token::Operator(super::super::Operator::LessEqual)
+                ),
+                (17, super::Token::Number(1.0)),
+                (18, super::Token::RightParenthesis),
+                (19, super::Token::End),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenizes_texts_with_escapes() {
+        assert_eq!(
+            tokens(r#"heating ~ "say \"hi\"" "#),
+            std::vec![
+                super::Token::Identifier("heating".to_string()),
+                super::Token::Operator(super::super::Operator::Contains),
+                super::Token::Text("say \"hi\"".to_string()),
+                super::Token::End,
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenizes_negative_numbers() {
+        assert_eq!(
+            tokens("score > -1.5 || score != -.5"),
+            std::vec![
+                super::Token::Identifier("score".to_string()),
+                super::Token::Operator(super::super::Operator::Greater),
+                super::Token::Number(-1.5),
+                super::Token::Or,
+                super::Token::Identifier("score".to_string()),
+                super::Token::Operator(super::super::Operator::NotEqual),
+                super::Token::Number(-0.5),
+                super::Token::End,
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_errors_with_columns() {
+        for (expression, column, message) in [
+            (r#"heating == "oil"#, 11, "Unterminated text"),
+            ("price < 1.2.3", 8, "Invalid number 1.2.3"),
+            ("price < 5 - 1", 10, "Unexpected character -"),
+            ("price # 1", 6, "Unexpected character #"),
+            ("price < 1 & year > 2", 10, "Unexpected character &"),
+        ] {
+            let error: super::super::ParseError = super::Token::tokenize(expression).unwrap_err();
+            assert_eq!(error.column, column, "{}", expression);
+            assert_eq!(error.message, message, "{}", expression);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+/// Value of a field or literal in filter expressions.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Number(std::primitive::f64),
+    Text(std::string::String),
+    /// Value of the field is not known for the house.
+    Unknown,
+}
+
+impl Value {
+    /// Number value from optional number.
+    ///
+    /// # Arguments
+    /// * `number` - Optional number.
+    pub(crate) fn number<N: Into<std::primitive::f64>>(number: std::option::Option<N>) -> Self {
+        match number {
+            Some(number) => Value::Number(number.into()),
+            None => Value::Unknown,
+        }
+    }
+
+    /// Text value from optional text.
+    ///
+    /// # Arguments
+    /// * `text` - Optional text.
+    pub(crate) fn text(text: std::option::Option<std::string::String>) -> Self {
+        match text {
+            Some(text) => Value::Text(text),
+            None => Value::Unknown,
+        }
+    }
+
+    /// Type of the value, None if unknown.
+    pub(super) fn kind(&self) -> std::option::Option<super::Kind> {
+        match self {
+            Value::Number(_) => Some(super::Kind::Number),
+            Value::Text(_) => Some(super::Kind::Text),
+            Value::Unknown => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Number(number) => write!(f, "{}", number),
+            Value::Text(text) => write!(f, "\"{}\"", text),
+            Value::Unknown => write!(f, "unknown"),
+        }
+    }
+}
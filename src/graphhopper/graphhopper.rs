@@ -0,0 +1,64 @@
+/// GraphHopper API client for self-hosted instance.
+pub(crate) struct GraphHopper {
+    base_url: std::string::String,
+}
+
+impl GraphHopper {
+    /// Create new GraphHopper API client.
+    ///
+    /// # Arguments
+    /// * `base_url` - Base URL for the instance, for example: http://localhost:8989
+    pub(crate) fn new(base_url: &std::primitive::str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Get biking distance in kilometers between two locations.
+    ///
+    /// # Arguments
+    /// * `from` - Starting location.
+    /// * `to` - Destination location.
+    pub(crate) async fn biking_km(
+        &self,
+        from: longitude::Location,
+        to: longitude::Location,
+    ) -> std::result::Result<std::primitive::u16, crate::client::JSONError> {
+        Ok((crate::client::Client::<crate::client::BetweenCalls>::new(
+            // Always caching bike routes, because they should not change.
+            Some("graphhopper/route/bike"),
+            // Self-hosted instance does not need rate limiting.
+            None,
+        )?
+        .get_json::<super::Response>(
+            &format!(
+                "{}/route?point={},{}&point={},{}&profile=bike&calc_points=false&instructions=false",
+                self.base_url, from.latitude, from.longitude, to.latitude, to.longitude
+            ),
+            None,
+        )
+        .await?
+        .paths[0]
+            .distance
+            / 1000.0)
+            .ceil() as std::primitive::u16)
+    }
+}
+
+impl crate::app::Routing for GraphHopper {
+    /// Biking distance in kilometers between two locations.
+    fn biking_km<'a>(
+        &'a self,
+        from: longitude::Location,
+        to: longitude::Location,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<
+                    Output = std::result::Result<std::primitive::u16, crate::client::JSONError>,
+                > + Send
+                + 'a,
+        >,
+    > {
+        std::boxed::Box::pin(GraphHopper::biking_km(self, from, to))
+    }
+}
@@ -0,0 +1,8 @@
+mod graphhopper;
+mod path;
+mod response;
+
+pub(crate) use self::graphhopper::GraphHopper;
+
+use self::path::Path;
+use self::response::Response;
@@ -0,0 +1,6 @@
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Path {
+    /// Distance in meters.
+    pub(super) distance: std::primitive::f64,
+}
@@ -0,0 +1,10 @@
+/// Escape text for HTML.
+///
+/// # Arguments
+/// * `text` - Text to escape.
+pub(crate) fn escape(text: &std::primitive::str) -> std::string::String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,3 @@
+mod escape;
+
+pub(crate) use self::escape::escape;
@@ -48,41 +48,58 @@
 use openssl as _;
 
 mod app;
+mod broadband;
 mod cache;
 mod client;
+mod coverage;
+mod digitransit;
 mod elisa;
+mod email;
 mod etuovi;
+mod filter;
+mod graphhopper;
+mod html;
+mod matrix;
+mod ntfy;
 mod open_route_service;
+mod osm_pbf;
+mod osrm;
+mod overpass;
+mod store;
 mod telegram;
+mod webhook;
 
 #[tokio::main]
 async fn main() -> std::result::Result<(), self::app::Error> {
     let args: self::app::Args = <self::app::Args as clap::Parser>::parse();
-    let telegram: std::option::Option<telegram::Telegram> = match args.telegram_bot_token.clone() {
-        Some(telegram_bot_token) => match args.telegram_user_id.clone() {
-            Some(telegram_user_id) => Some(telegram::Telegram::new(
-                &telegram_bot_token,
-                telegram_user_id,
-            )),
-            // Should not happen if clap is configured and working correctly.
-            None => std::panic!("--telegram-bot-token was given but not --telegram-user-id."),
-        },
-        None => {
-            if args.telegram_user_id.is_some() {
-                // Should not happen if clap is configured and working correctly.
-                std::panic!("--telegram-user-id was given but not --telegram-bot-token.");
+    // Errors are sent to admin chat if given, else to every notifier.
+    let notifiers: std::vec::Vec<std::sync::Arc<dyn self::app::Notifier>> =
+        match (&args.telegram_bot_token, args.telegram_admin_chat_id) {
+            (Some(telegram_bot_token), Some(telegram_admin_chat_id)) => {
+                std::vec![std::sync::Arc::new(telegram::Telegram::new(
+                    telegram_bot_token,
+                    std::vec![telegram_admin_chat_id],
+                    false,
+                    args.telegram_digest_top,
+                ))]
             }
-            None
-        }
+            _ => self::app::notifiers(&args, args.telegram_chat_ids()),
+        };
+    let result: std::result::Result<(), self::app::Error> = if args.telegram_bot {
+        self::app::bot(args).await
+    } else {
+        self::app::search(args).await
     };
-    match self::app::run(args, telegram.clone()).await {
+    match result {
         Ok(()) => Ok(()),
         Err(error) => {
             let message: std::string::String = format!("Got error: {:?}", error);
             eprintln!("{}", message);
             eprintln!("Backtrace: {:?}", std::backtrace::Backtrace::capture());
-            if let Some(telegram) = &telegram {
-                let _: teloxide::prelude::Message = telegram.send_message(&message).await?;
+            for notifier in &notifiers {
+                if let Err(error) = notifier.send_message(&message).await {
+                    eprintln!("Failed to send error: {}", error);
+                }
             }
             Err(error)
         }
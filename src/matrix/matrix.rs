@@ -0,0 +1,123 @@
+/// Matrix user that sends results to rooms.
+#[derive(Debug, Clone)]
+pub(crate) struct Matrix {
+    pub(self) homeserver: std::string::String,
+    pub(self) access_token: std::string::String,
+    pub(self) room_ids: std::vec::Vec<std::string::String>,
+}
+
+static LIMITER: once_cell::sync::Lazy<
+    std::sync::Arc<tokio::sync::Mutex<crate::client::BetweenCalls>>,
+> = once_cell::sync::Lazy::new(|| {
+    std::sync::Arc::new(tokio::sync::Mutex::new(crate::client::BetweenCalls::new(
+        500,
+    )))
+});
+
+/// Counter for unique transaction ids within the run.
+static TRANSACTIONS: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+impl Matrix {
+    /// Create new Matrix sender.
+    ///
+    /// # Arguments
+    /// * `homeserver` - Homeserver URL, for example https://matrix.org
+    /// * `access_token` - Access token for the user.
+    /// * `room_ids` - Room ids to send messages to.
+    pub(crate) fn new(
+        homeserver: &std::primitive::str,
+        access_token: &std::primitive::str,
+        room_ids: std::vec::Vec<std::string::String>,
+    ) -> Self {
+        Self {
+            homeserver: homeserver.trim_end_matches('/').to_string(),
+            access_token: access_token.to_string(),
+            room_ids,
+        }
+    }
+
+    /// Send message to every room.
+    ///
+    /// # Arguments
+    /// * `body` - Plain text message.
+    /// * `html` - Optional HTML formatted message.
+    pub(self) async fn send(
+        &self,
+        body: &std::primitive::str,
+        html: std::option::Option<&std::primitive::str>,
+    ) -> std::result::Result<(), crate::app::Error> {
+        let mut headers: reqwest::header::HeaderMap = reqwest::header::HeaderMap::new();
+        let _: bool = headers.append(
+            "Authorization",
+            reqwest::header::HeaderValue::from_str(&format!("Bearer {}", self.access_token))?,
+        );
+        let mut json: serde_json::Value = serde_json::json!({
+            "msgtype": "m.text",
+            "body": body,
+        });
+        if let Some(html) = html {
+            json["format"] = serde_json::Value::String("org.matrix.custom.html".to_string());
+            // Matrix HTML does not keep line breaks.
+            json["formatted_body"] = serde_json::Value::String(html.replace('\n', "<br>"));
+        }
+        let client: crate::client::Client<crate::client::BetweenCalls> =
+            crate::client::Client::new(None, Some(std::sync::Arc::clone(&LIMITER)))?;
+        for room_id in &self.room_ids {
+            client
+                .send_json(
+                    reqwest::Method::PUT,
+                    &format!(
+                        "{}/_matrix/client/v3/rooms/{}/send/m.room.message/house_finder_{}_{}",
+                        self.homeserver,
+                        url::form_urlencoded::byte_serialize(room_id.as_bytes())
+                            .collect::<std::string::String>(),
+                        chrono::Local::now().timestamp_millis(),
+                        TRANSACTIONS.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+                    ),
+                    json.clone(),
+                    Some(headers.clone()),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+impl crate::app::Notifier for Matrix {
+    /// Send every alert, house and summary.
+    fn notify<'a>(
+        &'a self,
+        notification: &'a crate::app::Notification,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<Output = std::result::Result<(), crate::app::Error>>
+                + Send
+                + 'a,
+        >,
+    > {
+        std::boxed::Box::pin(async move {
+            for alert in &notification.alerts {
+                self.send(&alert.message, None).await?;
+            }
+            for house in &notification.houses {
+                self.send(&house.message, Some(&house.message_html)).await?;
+            }
+            self.send(&notification.summary, None).await?;
+            Ok(())
+        })
+    }
+
+    /// Send plain text message.
+    fn send_message<'a>(
+        &'a self,
+        message: &'a std::primitive::str,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<Output = std::result::Result<(), crate::app::Error>>
+                + Send
+                + 'a,
+        >,
+    > {
+        std::boxed::Box::pin(async move { self.send(message, None).await })
+    }
+}
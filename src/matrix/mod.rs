@@ -0,0 +1,3 @@
+mod matrix;
+
+pub(crate) use self::matrix::Matrix;
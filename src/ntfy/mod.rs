@@ -0,0 +1,3 @@
+mod ntfy;
+
+pub(crate) use self::ntfy::Ntfy;
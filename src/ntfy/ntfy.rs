@@ -0,0 +1,135 @@
+/// ntfy topic that results are published to: https://docs.ntfy.sh/publish/
+#[derive(Debug, Clone)]
+pub(crate) struct Ntfy {
+    pub(self) base_url: std::string::String,
+    pub(self) topic: std::string::String,
+    pub(self) token: std::option::Option<std::string::String>,
+}
+
+/// Public ntfy server for topics given without server.
+const PUBLIC_URL: &std::primitive::str = "https://ntfy.sh";
+
+// Public ntfy server refills request limit once per 5 seconds after burst of 60, so being conservative.
+static LIMITER: once_cell::sync::Lazy<
+    std::sync::Arc<tokio::sync::Mutex<crate::client::BetweenCalls>>,
+> = once_cell::sync::Lazy::new(|| {
+    std::sync::Arc::new(tokio::sync::Mutex::new(crate::client::BetweenCalls::new(
+        1000,
+    )))
+});
+
+impl Ntfy {
+    /// Create new ntfy publisher.
+    ///
+    /// # Arguments
+    /// * `topic_url` - URL of the topic, for example https://ntfy.sh/my-houses, or only topic name for public server.
+    /// * `token` - Optional access token for the server.
+    pub(crate) fn new(
+        topic_url: &std::primitive::str,
+        token: std::option::Option<&std::primitive::str>,
+    ) -> Self {
+        let (base_url, topic): (&std::primitive::str, &std::primitive::str) =
+            match topic_url.trim_end_matches('/').rsplit_once('/') {
+                Some((base_url, topic)) => (base_url, topic),
+                None => (PUBLIC_URL, topic_url),
+            };
+        Self {
+            base_url: base_url.to_string(),
+            topic: topic.to_string(),
+            token: token.map(|token| token.to_string()),
+        }
+    }
+
+    /// Publish message to the topic.
+    ///
+    /// # Arguments
+    /// * `title` - Optional title of the message.
+    /// * `message` - Message.
+    /// * `click` - Optional URL to open when notification is clicked.
+    /// * `attach` - Optional URL of attached image.
+    /// * `tags` - Tags that are shown as emojis.
+    pub(self) async fn publish(
+        &self,
+        title: std::option::Option<&std::primitive::str>,
+        message: &std::primitive::str,
+        click: std::option::Option<&std::primitive::str>,
+        attach: std::option::Option<&std::primitive::str>,
+        tags: &[&std::primitive::str],
+    ) -> std::result::Result<(), crate::app::Error> {
+        let mut headers: reqwest::header::HeaderMap = reqwest::header::HeaderMap::new();
+        if let Some(token) = &self.token {
+            let _: bool = headers.append(
+                "Authorization",
+                reqwest::header::HeaderValue::from_str(&format!("Bearer {}", token))?,
+            );
+        }
+        let mut json: serde_json::Value = serde_json::json!({
+            "topic": self.topic,
+            "message": message,
+            "tags": tags,
+        });
+        for (key, value) in [("title", title), ("click", click), ("attach", attach)] {
+            if let Some(value) = value {
+                json[key] = serde_json::Value::String(value.to_string());
+            }
+        }
+        crate::client::Client::new(None, Some(std::sync::Arc::clone(&LIMITER)))?
+            .send_json(reqwest::Method::POST, &self.base_url, json, Some(headers))
+            .await?;
+        Ok(())
+    }
+}
+
+impl crate::app::Notifier for Ntfy {
+    /// Publish every alert, house and summary.
+    fn notify<'a>(
+        &'a self,
+        notification: &'a crate::app::Notification,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<Output = std::result::Result<(), crate::app::Error>>
+                + Send
+                + 'a,
+        >,
+    > {
+        std::boxed::Box::pin(async move {
+            for alert in &notification.alerts {
+                self.publish(
+                    Some("Price changed"),
+                    &alert.message,
+                    None,
+                    None,
+                    &["moneybag"],
+                )
+                .await?;
+            }
+            for house in &notification.houses {
+                self.publish(
+                    Some(&house.address),
+                    &house.message,
+                    Some(&house.url),
+                    house.photo_url.as_deref(),
+                    &["house"],
+                )
+                .await?;
+            }
+            self.publish(None, &notification.summary, None, None, &[])
+                .await?;
+            Ok(())
+        })
+    }
+
+    /// Publish plain text message.
+    fn send_message<'a>(
+        &'a self,
+        message: &'a std::primitive::str,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<Output = std::result::Result<(), crate::app::Error>>
+                + Send
+                + 'a,
+        >,
+    > {
+        std::boxed::Box::pin(async move { self.publish(None, message, None, None, &[]).await })
+    }
+}
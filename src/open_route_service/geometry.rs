@@ -0,0 +1,6 @@
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Geometry {
+    /// Coordinates as longitude and latitude.
+    pub(super) coordinates: std::vec::Vec<std::primitive::f64>,
+}
@@ -1,4 +1,7 @@
 mod error;
+mod feature;
+mod geocode_response;
+mod geometry;
 mod open_route_service;
 mod response;
 mod route;
@@ -7,6 +10,9 @@ mod summary;
 pub(crate) use self::error::Error;
 pub(crate) use self::open_route_service::OpenRouteService;
 
+use self::feature::Feature;
+use self::geocode_response::GeocodeResponse;
+use self::geometry::Geometry;
 pub(self) use self::response::Response;
 pub(self) use self::route::Route;
 pub(self) use self::summary::Summary;
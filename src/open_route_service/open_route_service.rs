@@ -1,8 +1,13 @@
 /// OpenRouteService API client.
 pub(crate) struct OpenRouteService {
+    base_url: std::string::String,
     headers: reqwest::header::HeaderMap,
+    limiter: std::option::Option<std::sync::Arc<tokio::sync::Mutex<crate::client::CallsPerMinute>>>,
 }
 
+/// Base URL for the public OpenRouteService API.
+const API_URL: &std::primitive::str = "https://api.openrouteservice.org";
+
 static LIMITER: once_cell::sync::Lazy<
     std::sync::Arc<tokio::sync::Mutex<crate::client::CallsPerMinute>>,
 > = once_cell::sync::Lazy::new(|| {
@@ -25,7 +30,24 @@ impl OpenRouteService {
             "Authorization",
             reqwest::header::HeaderValue::from_str(token)?,
         );
-        Ok(Self { headers })
+        Ok(Self {
+            base_url: API_URL.to_string(),
+            headers,
+            limiter: Some(std::sync::Arc::clone(&LIMITER)),
+        })
+    }
+
+    /// Create new client for self-hosted OpenRouteService.
+    /// Self-hosted instance does not need authorization or rate limiting.
+    ///
+    /// # Arguments
+    /// * `base_url` - Base URL for the self-hosted instance, for example: http://localhost:8080/ors
+    pub(crate) fn self_hosted(base_url: &std::primitive::str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            headers: reqwest::header::HeaderMap::new(),
+            limiter: None,
+        }
     }
 
     /// Get biking distance in kilometers between two locations.
@@ -41,10 +63,10 @@ impl OpenRouteService {
         Ok(crate::client::Client::new(
             // Always caching cycling directions, because the API is rate limited and they should not change.
             Some("open_route_service/directions/cycling-regular"),
-            Some(std::sync::Arc::clone(&LIMITER)),
+            self.limiter.clone(),
         )?
         .post_json::<super::Response>(
-            "https://api.openrouteservice.org/v2/directions/cycling-regular/json",
+            &format!("{}/v2/directions/cycling-regular/json", self.base_url),
             serde_json::json!(
                 {
                     "coordinates": [
@@ -69,4 +91,56 @@ impl OpenRouteService {
             .distance
             .ceil() as std::primitive::u16)
     }
+
+    /// Geocode address text to location.
+    ///
+    /// # Arguments
+    /// * `text` - Address text to search for.
+    pub(crate) async fn geocode(
+        &self,
+        text: &std::primitive::str,
+    ) -> std::result::Result<std::option::Option<longitude::Location>, crate::client::JSONError>
+    {
+        let features: std::vec::Vec<super::Feature> = crate::client::Client::new(
+            // Always caching geocoding results, because the API is rate limited and addresses should not move.
+            Some("open_route_service/geocode/search"),
+            self.limiter.clone(),
+        )?
+        .get_json::<super::GeocodeResponse>(
+            url::Url::parse_with_params(
+                &format!("{}/geocode/search", self.base_url),
+                &[("text", text), ("boundary.country", "FI"), ("size", "1")],
+            )
+            .map_err(crate::client::RequestError::from)?
+            .as_str(),
+            Some(self.headers.clone()),
+        )
+        .await?
+        .features;
+        Ok(match features.first() {
+            Some(feature) => match feature.geometry.coordinates.as_slice() {
+                [longitude, latitude, ..] => Some(longitude::Location::from(*latitude, *longitude)),
+                _ => None,
+            },
+            None => None,
+        })
+    }
+}
+
+impl crate::app::Routing for OpenRouteService {
+    /// Biking distance in kilometers between two locations.
+    fn biking_km<'a>(
+        &'a self,
+        from: longitude::Location,
+        to: longitude::Location,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<
+                    Output = std::result::Result<std::primitive::u16, crate::client::JSONError>,
+                > + Send
+                + 'a,
+        >,
+    > {
+        std::boxed::Box::pin(OpenRouteService::biking_km(self, from, to))
+    }
 }
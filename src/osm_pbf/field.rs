@@ -0,0 +1,104 @@
+/// Value of a Protocol Buffers field.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum Field<'a> {
+    Varint(std::primitive::u64),
+    Fixed64(std::primitive::u64),
+    Bytes(&'a [std::primitive::u8]),
+    Fixed32(std::primitive::u32),
+}
+
+impl<'a> Field<'a> {
+    /// Value as unsigned integer, zero for length delimited value.
+    pub(super) fn uint(&self) -> std::primitive::u64 {
+        match self {
+            Field::Varint(value) | Field::Fixed64(value) => *value,
+            Field::Fixed32(value) => *value as std::primitive::u64,
+            Field::Bytes(_) => 0,
+        }
+    }
+
+    /// Value as signed integer, for int32 and int64 fields.
+    pub(super) fn int(&self) -> std::primitive::i64 {
+        self.uint() as std::primitive::i64
+    }
+
+    /// Value as zigzag encoded signed integer, for sint32 and sint64 fields.
+    pub(super) fn sint(&self) -> std::primitive::i64 {
+        Self::zigzag(self.uint())
+    }
+
+    /// Value as bytes, empty for other than length delimited value.
+    pub(super) fn bytes(&self) -> &'a [std::primitive::u8] {
+        match self {
+            Field::Bytes(bytes) => bytes,
+            _ => &[],
+        }
+    }
+
+    /// Decode zigzag encoded signed integer.
+    ///
+    /// # Arguments
+    /// * `value` - Encoded value.
+    pub(super) fn zigzag(value: std::primitive::u64) -> std::primitive::i64 {
+        (value >> 1) as std::primitive::i64 ^ -((value & 1) as std::primitive::i64)
+    }
+
+    /// Packed repeated varints, or the single varint if the field is not packed.
+    pub(super) fn varints(
+        &self,
+    ) -> std::result::Result<std::vec::Vec<std::primitive::u64>, std::io::Error> {
+        match self {
+            Field::Bytes(bytes) => {
+                let mut fields: super::Fields<'a> = super::Fields::new(bytes);
+                let mut values: std::vec::Vec<std::primitive::u64> =
+                    std::vec::Vec::<std::primitive::u64>::new();
+                while !fields.is_empty() {
+                    values.push(fields.varint()?);
+                }
+                Ok(values)
+            }
+            _ => Ok(std::vec![self.uint()]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn decodes_zigzag() {
+        assert_eq!(super::Field::zigzag(0), 0);
+        assert_eq!(super::Field::zigzag(1), -1);
+        assert_eq!(super::Field::zigzag(2), 1);
+        assert_eq!(super::Field::zigzag(3), -2);
+        assert_eq!(
+            super::Field::zigzag(std::primitive::u64::MAX - 1),
+            std::primitive::i64::MAX
+        );
+        assert_eq!(
+            super::Field::zigzag(std::primitive::u64::MAX),
+            std::primitive::i64::MIN
+        );
+    }
+
+    #[test]
+    fn converts_integers() {
+        assert_eq!(super::Field::Varint(5).uint(), 5);
+        assert_eq!(super::Field::Varint(3).sint(), -2);
+        assert_eq!(super::Field::Varint(std::primitive::u64::MAX).int(), -1);
+        assert_eq!(super::Field::Fixed32(7).uint(), 7);
+        assert_eq!(super::Field::Bytes(&[1]).uint(), 0);
+        assert!(super::Field::Varint(1).bytes().is_empty());
+    }
+
+    #[test]
+    fn reads_packed_varints() {
+        assert_eq!(
+            super::Field::Bytes(&[0x01, 0xac, 0x02, 0x00])
+                .varints()
+                .unwrap(),
+            std::vec![1, 300, 0]
+        );
+        assert_eq!(super::Field::Varint(300).varints().unwrap(), std::vec![300]);
+        assert!(super::Field::Bytes(&[0x80]).varints().is_err());
+    }
+}
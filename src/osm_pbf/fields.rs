@@ -0,0 +1,150 @@
+/// Reader for fields of a Protocol Buffers message: https://protobuf.dev/programming-guides/encoding/
+pub(super) struct Fields<'a> {
+    pub(self) data: &'a [std::primitive::u8],
+    pub(self) position: std::primitive::usize,
+}
+
+impl<'a> Fields<'a> {
+    /// Create reader for the message.
+    ///
+    /// # Arguments
+    /// * `data` - Encoded message.
+    pub(super) fn new(data: &'a [std::primitive::u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    /// Is the whole message read?
+    pub(super) fn is_empty(&self) -> std::primitive::bool {
+        self.data.len() <= self.position
+    }
+
+    /// Error for invalid message.
+    pub(self) fn invalid() -> std::io::Error {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "Invalid Protocol Buffers message",
+        )
+    }
+
+    /// Read varint.
+    pub(super) fn varint(&mut self) -> std::result::Result<std::primitive::u64, std::io::Error> {
+        let mut value: std::primitive::u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let byte: std::primitive::u8 = *self.data.get(self.position).ok_or(Self::invalid())?;
+            self.position += 1;
+            value |= ((byte & 0x7f) as std::primitive::u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(Self::invalid())
+    }
+
+    /// Read given number of bytes.
+    ///
+    /// # Arguments
+    /// * `length` - Number of bytes.
+    pub(self) fn take(
+        &mut self,
+        length: std::primitive::usize,
+    ) -> std::result::Result<&'a [std::primitive::u8], std::io::Error> {
+        let end: std::primitive::usize = self
+            .position
+            .checked_add(length)
+            .filter(|end| *end <= self.data.len())
+            .ok_or(Self::invalid())?;
+        let bytes: &'a [std::primitive::u8] = &self.data[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    /// Read next field.
+    ///
+    /// # Returns
+    /// Field number and value, None if the whole message is read.
+    pub(super) fn next_field(
+        &mut self,
+    ) -> std::result::Result<
+        std::option::Option<(std::primitive::u64, super::Field<'a>)>,
+        std::io::Error,
+    > {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        let key: std::primitive::u64 = self.varint()?;
+        let field: super::Field<'a> =
+            match key & 0x7 {
+                0 => super::Field::Varint(self.varint()?),
+                1 => super::Field::Fixed64(std::primitive::u64::from_le_bytes(
+                    self.take(8)?.try_into().map_err(|_| Self::invalid())?,
+                )),
+                2 => {
+                    let length: std::primitive::u64 = self.varint()?;
+                    super::Field::Bytes(self.take(
+                        std::primitive::usize::try_from(length).map_err(|_| Self::invalid())?,
+                    )?)
+                }
+                5 => super::Field::Fixed32(std::primitive::u32::from_le_bytes(
+                    self.take(4)?.try_into().map_err(|_| Self::invalid())?,
+                )),
+                _ => return Err(Self::invalid()),
+            };
+        Ok(Some((key >> 3, field)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn reads_every_wire_type() {
+        let data: [std::primitive::u8; 24] = [
+            // Field 1 varint 300.
+            0x08, 0xac, 0x02, //
+            // Field 2 fixed64 1.
+            0x11, 1, 0, 0, 0, 0, 0, 0, 0, //
+            // Field 3 bytes "ab".
+            0x1a, 2, b'a', b'b', //
+            // Field 4 fixed32 2.
+            0x25, 2, 0, 0, 0, //
+            // Field 16 varint 1 has two byte key.
+            0x80, 0x01, 1,
+        ];
+        let mut fields: super::Fields<'_> = super::Fields::new(&data);
+        assert!(matches!(
+            fields.next_field().unwrap(),
+            Some((1, crate::osm_pbf::Field::Varint(300)))
+        ));
+        assert!(matches!(
+            fields.next_field().unwrap(),
+            Some((2, crate::osm_pbf::Field::Fixed64(1)))
+        ));
+        assert!(matches!(
+            fields.next_field().unwrap(),
+            Some((3, crate::osm_pbf::Field::Bytes(b"ab")))
+        ));
+        assert!(matches!(
+            fields.next_field().unwrap(),
+            Some((4, crate::osm_pbf::Field::Fixed32(2)))
+        ));
+        assert!(matches!(
+            fields.next_field().unwrap(),
+            Some((16, crate::osm_pbf::Field::Varint(1)))
+        ));
+        assert!(fields.is_empty());
+        assert!(fields.next_field().unwrap().is_none());
+    }
+
+    #[test]
+    fn rejects_invalid_messages() {
+        // Unsupported wire type 3.
+        assert!(super::Fields::new(&[0x0b]).next_field().is_err());
+        // Bytes longer than the message.
+        assert!(super::Fields::new(&[0x0a, 5, 1]).next_field().is_err());
+        // Truncated fixed32.
+        assert!(super::Fields::new(&[0x0d, 1, 2]).next_field().is_err());
+        // Varint without end.
+        assert!(super::Fields::new(&[0x08, 0xff]).next_field().is_err());
+        // Varint over 64 bits.
+        assert!(super::Fields::new(&[0xff; 11]).varint().is_err());
+    }
+}
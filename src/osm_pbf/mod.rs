@@ -0,0 +1,13 @@
+mod field;
+mod fields;
+mod osm_amenity;
+mod osm_pbf;
+mod primitive_block;
+
+pub(crate) use self::osm_pbf::OsmPbf;
+
+use self::field::Field;
+use self::fields::Fields;
+use self::osm_amenity::OsmAmenity;
+use self::primitive_block::PrimitiveBlock;
+use self::primitive_block::Tags;
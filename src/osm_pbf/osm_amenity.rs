@@ -0,0 +1,9 @@
+/// Amenity found from OpenStreetMap extract, stored to cache.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OsmAmenity {
+    /// Amenity argument value, for example GROCERY.
+    pub(super) amenity: std::string::String,
+    pub(super) lat: std::primitive::f64,
+    pub(super) lon: std::primitive::f64,
+}
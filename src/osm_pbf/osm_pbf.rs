@@ -0,0 +1,467 @@
+/// Amenities from local OpenStreetMap `.osm.pbf` extract, for example from https://download.geofabrik.de/europe/finland.html
+/// Extract is read once and the amenities in it are cached, so that it is read again only if the file changes.
+pub(crate) struct OsmPbf {
+    path: std::string::String,
+    radius_km: std::primitive::f64,
+    amenities: tokio::sync::OnceCell<std::vec::Vec<(crate::app::Amenity, longitude::Location)>>,
+}
+
+impl OsmPbf {
+    /// Create new amenity source for the extract.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the `.osm.pbf` file.
+    /// * `radius_km` - Radius in kilometers to search amenities from.
+    pub(crate) fn new(path: &std::primitive::str, radius_km: std::primitive::f64) -> Self {
+        Self {
+            path: path.to_string(),
+            radius_km,
+            amenities: tokio::sync::OnceCell::new(),
+        }
+    }
+
+    /// Error for invalid extract.
+    ///
+    /// # Arguments
+    /// * `message` - Error message.
+    pub(self) fn invalid(message: &std::primitive::str) -> std::io::Error {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, message.to_string())
+    }
+
+    /// Read every data block of the extract.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the `.osm.pbf` file.
+    /// * `function` - Function that gets every primitive block.
+    pub(self) fn blocks<
+        F: FnMut(&super::PrimitiveBlock<'_>) -> std::result::Result<(), std::io::Error>,
+    >(
+        path: &std::primitive::str,
+        mut function: F,
+    ) -> std::result::Result<(), std::io::Error> {
+        let mut reader: std::io::BufReader<std::fs::File> =
+            std::io::BufReader::new(std::fs::File::open(path)?);
+        loop {
+            let mut length: [std::primitive::u8; 4] = [0; 4];
+            match std::io::Read::read_exact(&mut reader, &mut length) {
+                Ok(()) => {}
+                Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(()),
+                Err(error) => return Err(error),
+            }
+            let header_size: std::primitive::u32 = std::primitive::u32::from_be_bytes(length);
+            // Sizes come from the file, so they are checked before allocating.
+            if MAX_BLOB_HEADER_SIZE < header_size {
+                return Err(Self::invalid(&format!(
+                    "BlobHeader of {} bytes is over the limit of {} bytes",
+                    header_size, MAX_BLOB_HEADER_SIZE
+                )));
+            }
+            let mut header: std::vec::Vec<std::primitive::u8> =
+                std::vec![0; header_size as std::primitive::usize];
+            std::io::Read::read_exact(&mut reader, &mut header)?;
+            let mut kind: &[std::primitive::u8] = &[];
+            let mut size: std::primitive::u64 = 0;
+            let mut fields: super::Fields<'_> = super::Fields::new(&header);
+            while let Some((number, field)) = fields.next_field()? {
+                match number {
+                    1 => kind = field.bytes(),
+                    3 => size = field.uint(),
+                    _ => {}
+                }
+            }
+            if MAX_BLOB_SIZE < size {
+                return Err(Self::invalid(&format!(
+                    "Blob of {} bytes is over the limit of {} bytes",
+                    size, MAX_BLOB_SIZE
+                )));
+            }
+            let mut blob: std::vec::Vec<std::primitive::u8> =
+                std::vec![0; size as std::primitive::usize];
+            std::io::Read::read_exact(&mut reader, &mut blob)?;
+            if kind != b"OSMData" {
+                continue;
+            }
+            let mut data: std::option::Option<std::vec::Vec<std::primitive::u8>> = None;
+            let mut fields: super::Fields<'_> = super::Fields::new(&blob);
+            while let Some((number, field)) = fields.next_field()? {
+                match number {
+                    1 => data = Some(field.bytes().to_vec()),
+                    3 => {
+                        let mut decompressed: std::vec::Vec<std::primitive::u8> =
+                            std::vec::Vec::<std::primitive::u8>::new();
+                        let _: std::primitive::usize = std::io::Read::read_to_end(
+                            &mut std::io::Read::take(
+                                flate2::read::ZlibDecoder::new(field.bytes()),
+                                MAX_BLOB_SIZE + 1,
+                            ),
+                            &mut decompressed,
+                        )?;
+                        if MAX_BLOB_SIZE < decompressed.len() as std::primitive::u64 {
+                            return Err(Self::invalid(&format!(
+                                "Decompressed blob is over the limit of {} bytes",
+                                MAX_BLOB_SIZE
+                            )));
+                        }
+                        data = Some(decompressed);
+                    }
+                    _ => {}
+                }
+            }
+            function(&super::PrimitiveBlock::parse(&data.ok_or(
+                Self::invalid(
+                    "Only uncompressed and zlib compressed .osm.pbf blocks are supported",
+                ),
+            )?)?)?;
+        }
+    }
+
+    /// Amenities that match the tags.
+    ///
+    /// # Arguments
+    /// * `tags` - Tags of the node or way.
+    pub(self) fn matching(tags: &super::Tags<'_>) -> std::vec::Vec<crate::app::Amenity> {
+        if !tags
+            .iter()
+            .any(|(key, _)| crate::app::Amenity::TAG_KEYS.contains(key))
+        {
+            return std::vec::Vec::<crate::app::Amenity>::new();
+        }
+        let tags: std::collections::HashMap<std::string::String, std::string::String> = tags
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        crate::app::Amenity::ALL
+            .into_iter()
+            .filter(|amenity| amenity.matches(&tags))
+            .collect()
+    }
+
+    /// Read amenities from the extract.
+    /// Ways are located to the center of their nodes like Overpass does, relations are not read.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the `.osm.pbf` file.
+    pub(self) fn read(
+        path: &std::primitive::str,
+    ) -> std::result::Result<std::vec::Vec<super::OsmAmenity>, std::io::Error> {
+        let mut amenities: std::vec::Vec<super::OsmAmenity> =
+            std::vec::Vec::<super::OsmAmenity>::new();
+        let mut ways: std::vec::Vec<(
+            std::vec::Vec<crate::app::Amenity>,
+            std::vec::Vec<std::primitive::i64>,
+        )> = std::vec::Vec::new();
+        let mut way_nodes: std::collections::HashMap<
+            std::primitive::i64,
+            std::option::Option<(std::primitive::f64, std::primitive::f64)>,
+        > = std::collections::HashMap::new();
+        // First pass finds the amenities and nodes of the amenity ways.
+        Self::blocks(path, |block| {
+            block.nodes(|_, lat, lon, tags| {
+                for amenity in Self::matching(&tags) {
+                    amenities.push(super::OsmAmenity {
+                        amenity: amenity.name().to_string(),
+                        lat,
+                        lon,
+                    });
+                }
+            })?;
+            block.ways(|tags, refs| {
+                let matching: std::vec::Vec<crate::app::Amenity> = Self::matching(&tags);
+                if !matching.is_empty() {
+                    for id in &refs {
+                        let _: std::option::Option<
+                            std::option::Option<(std::primitive::f64, std::primitive::f64)>,
+                        > = way_nodes.insert(*id, None);
+                    }
+                    ways.push((matching, refs));
+                }
+            })
+        })?;
+        // Second pass finds the locations of the way nodes.
+        if !ways.is_empty() {
+            Self::blocks(path, |block| {
+                block.nodes(|id, lat, lon, _| {
+                    if let Some(location) = way_nodes.get_mut(&id) {
+                        *location = Some((lat, lon));
+                    }
+                })
+            })?;
+        }
+        for (matching, refs) in ways {
+            let locations: std::vec::Vec<(std::primitive::f64, std::primitive::f64)> = refs
+                .iter()
+                .filter_map(|id| way_nodes.get(id).copied().flatten())
+                .collect();
+            if locations.is_empty() {
+                continue;
+            }
+            // Center of the bounding box.
+            let (lat_min, lat_max, lon_min, lon_max): (
+                std::primitive::f64,
+                std::primitive::f64,
+                std::primitive::f64,
+                std::primitive::f64,
+            ) = locations.iter().fold(
+                (
+                    std::primitive::f64::MAX,
+                    std::primitive::f64::MIN,
+                    std::primitive::f64::MAX,
+                    std::primitive::f64::MIN,
+                ),
+                |(lat_min, lat_max, lon_min, lon_max), (lat, lon)| {
+                    (
+                        lat_min.min(*lat),
+                        lat_max.max(*lat),
+                        lon_min.min(*lon),
+                        lon_max.max(*lon),
+                    )
+                },
+            );
+            for amenity in matching {
+                amenities.push(super::OsmAmenity {
+                    amenity: amenity.name().to_string(),
+                    lat: (lat_min + lat_max) / 2.0,
+                    lon: (lon_min + lon_max) / 2.0,
+                });
+            }
+        }
+        Ok(amenities)
+    }
+
+    /// Amenities of the extract from cache, or read from the extract.
+    pub(self) async fn amenities(
+        &self,
+    ) -> std::result::Result<
+        &std::vec::Vec<(crate::app::Amenity, longitude::Location)>,
+        std::io::Error,
+    > {
+        return self
+            .amenities
+            .get_or_try_init(|| async {
+                let metadata: std::fs::Metadata = std::fs::metadata(&self.path)?;
+                // Cache is for this version of the file.
+                let cache_key: std::string::String = format!(
+                    "{}:{}:{:?}",
+                    self.path,
+                    metadata.len(),
+                    metadata.modified()?
+                );
+                let cache: crate::cache::Cache = crate::cache::Cache::new("osm_pbf", "json")?;
+                let amenities: std::vec::Vec<super::OsmAmenity> = if cache.exists(&cache_key) {
+                    serde_json::from_str(&cache.read(&cache_key)?)?
+                } else {
+                    eprintln!(
+                        "Reading amenities from {}, this can take a while.",
+                        self.path
+                    );
+                    let path: std::string::String = self.path.clone();
+                    let amenities: std::vec::Vec<super::OsmAmenity> =
+                        tokio::task::spawn_blocking(move || Self::read(&path))
+                            .await
+                            .map_err(std::io::Error::other)??;
+                    cache.write(&cache_key, &serde_json::to_string(&amenities)?)?;
+                    amenities
+                };
+                Ok(amenities
+                    .into_iter()
+                    .filter_map(|amenity| {
+                        crate::app::Amenity::from_name(&amenity.amenity)
+                            .map(|name| (name, longitude::Location::from(amenity.lat, amenity.lon)))
+                    })
+                    .collect())
+            })
+            .await;
+    }
+
+    /// Straight line distances in kilometers to nearest amenities.
+    /// Amenities not found within the radius are missing from the map.
+    ///
+    /// # Arguments
+    /// * `location` - Location to search around.
+    pub(crate) async fn nearest_km(
+        &self,
+        location: longitude::Location,
+    ) -> std::result::Result<crate::app::AmenityKm, crate::client::JSONError> {
+        let mut nearest_km: crate::app::AmenityKm = std::collections::HashMap::new();
+        for (amenity, amenity_location) in self
+            .amenities()
+            .await
+            .map_err(crate::client::RequestError::from)?
+        {
+            let km: std::primitive::f64 = amenity_location.distance(&location).kilometers();
+            if km <= self.radius_km {
+                let _: &mut std::primitive::f64 = nearest_km
+                    .entry(*amenity)
+                    .and_modify(|nearest| *nearest = nearest.min(km))
+                    .or_insert(km);
+            }
+        }
+        Ok(nearest_km)
+    }
+}
+
+/// Maximum size of BlobHeader in bytes by the PBF format.
+const MAX_BLOB_HEADER_SIZE: std::primitive::u32 = 64 * 1024;
+
+/// Maximum size of Blob in bytes by the PBF format.
+const MAX_BLOB_SIZE: std::primitive::u64 = 32 * 1024 * 1024;
+
+impl crate::app::Amenities for OsmPbf {
+    /// Straight line distances in kilometers to nearest amenities.
+    fn nearest_km<'a>(
+        &'a self,
+        location: longitude::Location,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<
+                    Output = std::result::Result<crate::app::AmenityKm, crate::client::JSONError>,
+                > + Send
+                + 'a,
+        >,
+    > {
+        std::boxed::Box::pin(OsmPbf::nearest_km(self, location))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    /// Read blocks of a temporary file with the content.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the temporary file.
+    /// * `content` - Content of the file.
+    fn blocks(
+        name: &std::primitive::str,
+        content: &[std::primitive::u8],
+    ) -> std::result::Result<std::primitive::usize, std::io::Error> {
+        let path: std::path::PathBuf =
+            std::env::temp_dir().join(format!("house_finder_{}.osm.pbf", name));
+        std::fs::write(&path, content)?;
+        let mut count: std::primitive::usize = 0;
+        let result: std::result::Result<(), std::io::Error> =
+            super::OsmPbf::blocks(&path.to_string_lossy(), |_| {
+                count += 1;
+                Ok(())
+            });
+        std::fs::remove_file(&path)?;
+        result.map(|()| count)
+    }
+
+    #[test]
+    fn rejects_too_large_blob_header() {
+        assert!(
+            blocks("large_header", &std::primitive::u32::MAX.to_be_bytes())
+                .unwrap_err()
+                .to_string()
+                .contains("over the limit")
+        );
+    }
+
+    #[test]
+    fn rejects_too_large_blob() {
+        // BlobHeader with type OSMData and datasize of 4 GiB.
+        let mut header: std::vec::Vec<std::primitive::u8> = std::vec![0x0a, 7];
+        header.extend(b"OSMData");
+        header.extend([0x18, 0x80, 0x80, 0x80, 0x80, 0x10]);
+        let mut content: std::vec::Vec<std::primitive::u8> =
+            (header.len() as std::primitive::u32).to_be_bytes().to_vec();
+        content.extend(header);
+        assert!(blocks("large_blob", &content)
+            .unwrap_err()
+            .to_string()
+            .contains("over the limit"));
+    }
+
+    #[test]
+    fn reads_empty_file() {
+        assert_eq!(blocks("empty", &[]).unwrap(), 0);
+    }
+
+    /// Encode file block with header.
+    ///
+    /// # Arguments
+    /// * `kind` - Type of the block.
+    /// * `blob` - Blob message.
+    fn file_block(
+        kind: &std::primitive::str,
+        blob: &[std::primitive::u8],
+    ) -> std::vec::Vec<std::primitive::u8> {
+        let mut header: std::vec::Vec<std::primitive::u8> =
+            crate::osm_pbf::primitive_block::tests::bytes_field(1, kind.as_bytes());
+        header.push(3 << 3);
+        header.extend(crate::osm_pbf::primitive_block::tests::varint(
+            blob.len() as std::primitive::u64
+        ));
+        let mut content: std::vec::Vec<std::primitive::u8> =
+            (header.len() as std::primitive::u32).to_be_bytes().to_vec();
+        content.extend(header);
+        content.extend(blob);
+        content
+    }
+
+    #[test]
+    fn reads_amenities_from_nodes_and_ways() {
+        let strings: [&std::primitive::str; 5] = ["", "shop", "supermarket", "amenity", "school"];
+        // Uncompressed block with a supermarket node and nodes of the school way.
+        let nodes: std::vec::Vec<std::primitive::u8> =
+            crate::osm_pbf::primitive_block::tests::block(
+                &strings,
+                &crate::osm_pbf::primitive_block::tests::dense_group(
+                    &[1, 2, 3],
+                    &[615_000_000, 600_000_000, 602_000_000],
+                    &[238_000_000, 250_000_000, 254_000_000],
+                    &[1, 2, 0, 0, 0],
+                ),
+            );
+        // Zlib compressed block with the school way.
+        let mut way: std::vec::Vec<std::primitive::u8> =
+            crate::osm_pbf::primitive_block::tests::packed_field(2, &[3]);
+        way.extend(crate::osm_pbf::primitive_block::tests::packed_field(
+            3,
+            &[4],
+        ));
+        way.extend(crate::osm_pbf::primitive_block::tests::packed_field(
+            8,
+            &[
+                crate::osm_pbf::primitive_block::tests::zigzag(2),
+                crate::osm_pbf::primitive_block::tests::zigzag(1),
+            ],
+        ));
+        let ways: std::vec::Vec<std::primitive::u8> = crate::osm_pbf::primitive_block::tests::block(
+            &strings,
+            &crate::osm_pbf::primitive_block::tests::bytes_field(3, &way),
+        );
+        let mut encoder: flate2::write::ZlibEncoder<std::vec::Vec<std::primitive::u8>> =
+            flate2::write::ZlibEncoder::new(
+                std::vec::Vec::<std::primitive::u8>::new(),
+                flate2::Compression::default(),
+            );
+        std::io::Write::write_all(&mut encoder, &ways).unwrap();
+        let mut content: std::vec::Vec<std::primitive::u8> = file_block("OSMHeader", &[]);
+        content.extend(file_block(
+            "OSMData",
+            &crate::osm_pbf::primitive_block::tests::bytes_field(1, &nodes),
+        ));
+        content.extend(file_block(
+            "OSMData",
+            &crate::osm_pbf::primitive_block::tests::bytes_field(3, &encoder.finish().unwrap()),
+        ));
+
+        let path: std::path::PathBuf = std::env::temp_dir().join("house_finder_amenities.osm.pbf");
+        std::fs::write(&path, content).unwrap();
+        let amenities: std::result::Result<
+            std::vec::Vec<crate::osm_pbf::OsmAmenity>,
+            std::io::Error,
+        > = super::OsmPbf::read(&path.to_string_lossy());
+        std::fs::remove_file(&path).unwrap();
+        let amenities: std::vec::Vec<crate::osm_pbf::OsmAmenity> = amenities.unwrap();
+        assert_eq!(amenities.len(), 2);
+        assert_eq!(amenities[0].amenity, "GROCERY");
+        assert!((amenities[0].lat - 61.5).abs() < 0.000001);
+        assert!((amenities[0].lon - 23.8).abs() < 0.000001);
+        // Way is located to the center of its nodes.
+        assert_eq!(amenities[1].amenity, "SCHOOL");
+        assert!((amenities[1].lat - 60.1).abs() < 0.000001);
+        assert!((amenities[1].lon - 25.2).abs() < 0.000001);
+    }
+}
@@ -0,0 +1,538 @@
+/// Block of OpenStreetMap nodes and ways: https://wiki.openstreetmap.org/wiki/PBF_Format
+pub(super) struct PrimitiveBlock<'a> {
+    pub(self) strings: std::vec::Vec<&'a std::primitive::str>,
+    pub(self) groups: std::vec::Vec<&'a [std::primitive::u8]>,
+    pub(self) granularity: std::primitive::i64,
+    pub(self) lat_offset: std::primitive::i64,
+    pub(self) lon_offset: std::primitive::i64,
+}
+
+/// Tags of a node or way as key and value pairs.
+pub(super) type Tags<'a> = std::vec::Vec<(&'a std::primitive::str, &'a std::primitive::str)>;
+
+impl<'a> PrimitiveBlock<'a> {
+    /// Parse block.
+    ///
+    /// # Arguments
+    /// * `data` - Decompressed PrimitiveBlock message.
+    pub(super) fn parse(
+        data: &'a [std::primitive::u8],
+    ) -> std::result::Result<Self, std::io::Error> {
+        let mut block: Self = Self {
+            strings: std::vec::Vec::<&'a std::primitive::str>::new(),
+            groups: std::vec::Vec::<&'a [std::primitive::u8]>::new(),
+            granularity: 100,
+            lat_offset: 0,
+            lon_offset: 0,
+        };
+        let mut fields: super::Fields<'a> = super::Fields::new(data);
+        while let Some((number, field)) = fields.next_field()? {
+            match number {
+                1 => {
+                    let mut strings: super::Fields<'a> = super::Fields::new(field.bytes());
+                    while let Some((number, field)) = strings.next_field()? {
+                        if number == 1 {
+                            // Tags with invalid UTF-8 do not match any amenity.
+                            block
+                                .strings
+                                .push(std::str::from_utf8(field.bytes()).unwrap_or(""));
+                        }
+                    }
+                }
+                2 => block.groups.push(field.bytes()),
+                17 => block.granularity = field.int(),
+                19 => block.lat_offset = field.int(),
+                20 => block.lon_offset = field.int(),
+                _ => {}
+            }
+        }
+        Ok(block)
+    }
+
+    /// String from the string table.
+    ///
+    /// # Arguments
+    /// * `index` - Index of the string.
+    pub(self) fn string(&self, index: std::primitive::u64) -> &'a std::primitive::str {
+        self.strings
+            .get(index as std::primitive::usize)
+            .copied()
+            .unwrap_or("")
+    }
+
+    /// Tags from key and value string indexes.
+    ///
+    /// # Arguments
+    /// * `keys` - Indexes of the keys.
+    /// * `values` - Indexes of the values.
+    pub(self) fn tags(
+        &self,
+        keys: &[std::primitive::u64],
+        values: &[std::primitive::u64],
+    ) -> Tags<'a> {
+        keys.iter()
+            .zip(values)
+            .map(|(key, value)| (self.string(*key), self.string(*value)))
+            .collect()
+    }
+
+    /// Latitude or longitude in degrees.
+    ///
+    /// # Arguments
+    /// * `offset` - Offset of the block in nanodegrees.
+    /// * `value` - Value in granularity units.
+    pub(self) fn degrees(
+        &self,
+        offset: std::primitive::i64,
+        value: std::primitive::i64,
+    ) -> std::primitive::f64 {
+        // Calculated as floats, so that values of invalid block can not overflow.
+        0.000000001
+            * (offset as std::primitive::f64
+                + self.granularity as std::primitive::f64 * value as std::primitive::f64)
+    }
+
+    /// Add zigzag encoded delta to the previous value of delta coded field.
+    ///
+    /// # Arguments
+    /// * `value` - Previous value.
+    /// * `delta` - Zigzag encoded delta.
+    pub(self) fn add_delta(
+        value: std::primitive::i64,
+        delta: std::primitive::u64,
+    ) -> std::result::Result<std::primitive::i64, std::io::Error> {
+        value
+            .checked_add(super::Field::zigzag(delta))
+            .ok_or(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Delta coded value overflows",
+            ))
+    }
+
+    /// Call the function for every node in the block.
+    ///
+    /// # Arguments
+    /// * `function` - Function that gets id, latitude, longitude and tags of the node.
+    pub(super) fn nodes<
+        F: FnMut(std::primitive::i64, std::primitive::f64, std::primitive::f64, Tags<'a>),
+    >(
+        &self,
+        mut function: F,
+    ) -> std::result::Result<(), std::io::Error> {
+        for group in &self.groups {
+            let mut fields: super::Fields<'a> = super::Fields::new(group);
+            while let Some((number, field)) = fields.next_field()? {
+                match number {
+                    1 => {
+                        let (mut id, mut lat, mut lon): (
+                            std::primitive::i64,
+                            std::primitive::i64,
+                            std::primitive::i64,
+                        ) = (0, 0, 0);
+                        let mut keys: std::vec::Vec<std::primitive::u64> =
+                            std::vec::Vec::<std::primitive::u64>::new();
+                        let mut values: std::vec::Vec<std::primitive::u64> =
+                            std::vec::Vec::<std::primitive::u64>::new();
+                        let mut node: super::Fields<'a> = super::Fields::new(field.bytes());
+                        while let Some((number, field)) = node.next_field()? {
+                            match number {
+                                1 => id = field.sint(),
+                                2 => keys.extend(field.varints()?),
+                                3 => values.extend(field.varints()?),
+                                8 => lat = field.sint(),
+                                9 => lon = field.sint(),
+                                _ => {}
+                            }
+                        }
+                        function(
+                            id,
+                            self.degrees(self.lat_offset, lat),
+                            self.degrees(self.lon_offset, lon),
+                            self.tags(&keys, &values),
+                        );
+                    }
+                    2 => self.dense_nodes(field.bytes(), &mut function)?,
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Call the function for every node in the dense nodes.
+    ///
+    /// # Arguments
+    /// * `data` - DenseNodes message.
+    /// * `function` - Function that gets id, latitude, longitude and tags of the node.
+    pub(self) fn dense_nodes<
+        F: FnMut(std::primitive::i64, std::primitive::f64, std::primitive::f64, Tags<'a>),
+    >(
+        &self,
+        data: &'a [std::primitive::u8],
+        function: &mut F,
+    ) -> std::result::Result<(), std::io::Error> {
+        let mut ids: std::vec::Vec<std::primitive::u64> =
+            std::vec::Vec::<std::primitive::u64>::new();
+        let mut lats: std::vec::Vec<std::primitive::u64> =
+            std::vec::Vec::<std::primitive::u64>::new();
+        let mut lons: std::vec::Vec<std::primitive::u64> =
+            std::vec::Vec::<std::primitive::u64>::new();
+        let mut keys_values: std::vec::Vec<std::primitive::u64> =
+            std::vec::Vec::<std::primitive::u64>::new();
+        let mut fields: super::Fields<'a> = super::Fields::new(data);
+        while let Some((number, field)) = fields.next_field()? {
+            match number {
+                1 => ids.extend(field.varints()?),
+                8 => lats.extend(field.varints()?),
+                9 => lons.extend(field.varints()?),
+                10 => keys_values.extend(field.varints()?),
+                _ => {}
+            }
+        }
+        // Ids and coordinates are delta coded and keys and values of nodes are separated by zero.
+        let mut keys_values: std::slice::Iter<'_, std::primitive::u64> = keys_values.iter();
+        let (mut id, mut lat, mut lon): (
+            std::primitive::i64,
+            std::primitive::i64,
+            std::primitive::i64,
+        ) = (0, 0, 0);
+        for ((id_delta, lat_delta), lon_delta) in ids.iter().zip(&lats).zip(&lons) {
+            id = Self::add_delta(id, *id_delta)?;
+            lat = Self::add_delta(lat, *lat_delta)?;
+            lon = Self::add_delta(lon, *lon_delta)?;
+            let mut tags: Tags<'a> = Tags::<'a>::new();
+            while let Some(key) = keys_values.next() {
+                if *key == 0 {
+                    break;
+                }
+                if let Some(value) = keys_values.next() {
+                    tags.push((self.string(*key), self.string(*value)));
+                }
+            }
+            function(
+                id,
+                self.degrees(self.lat_offset, lat),
+                self.degrees(self.lon_offset, lon),
+                tags,
+            );
+        }
+        Ok(())
+    }
+
+    /// Call the function for every way in the block.
+    ///
+    /// # Arguments
+    /// * `function` - Function that gets tags and node ids of the way.
+    pub(super) fn ways<F: FnMut(Tags<'a>, std::vec::Vec<std::primitive::i64>)>(
+        &self,
+        mut function: F,
+    ) -> std::result::Result<(), std::io::Error> {
+        for group in &self.groups {
+            let mut fields: super::Fields<'a> = super::Fields::new(group);
+            while let Some((number, field)) = fields.next_field()? {
+                if number != 3 {
+                    continue;
+                }
+                let mut keys: std::vec::Vec<std::primitive::u64> =
+                    std::vec::Vec::<std::primitive::u64>::new();
+                let mut values: std::vec::Vec<std::primitive::u64> =
+                    std::vec::Vec::<std::primitive::u64>::new();
+                let mut refs: std::vec::Vec<std::primitive::i64> =
+                    std::vec::Vec::<std::primitive::i64>::new();
+                let mut way: super::Fields<'a> = super::Fields::new(field.bytes());
+                while let Some((number, field)) = way.next_field()? {
+                    match number {
+                        2 => keys.extend(field.varints()?),
+                        3 => values.extend(field.varints()?),
+                        8 => {
+                            // Node ids are delta coded.
+                            let mut id: std::primitive::i64 = 0;
+                            for delta in field.varints()? {
+                                id = Self::add_delta(id, delta)?;
+                                refs.push(id);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                function(self.tags(&keys, &values), refs);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    /// Encode varint.
+    ///
+    /// # Arguments
+    /// * `value` - Value to encode.
+    pub(in crate::osm_pbf) fn varint(
+        mut value: std::primitive::u64,
+    ) -> std::vec::Vec<std::primitive::u8> {
+        let mut bytes: std::vec::Vec<std::primitive::u8> =
+            std::vec::Vec::<std::primitive::u8>::new();
+        while 0x80 <= value {
+            bytes.push((value & 0x7f) as std::primitive::u8 | 0x80);
+            value >>= 7;
+        }
+        bytes.push(value as std::primitive::u8);
+        bytes
+    }
+
+    /// Encode signed value with zigzag encoding.
+    ///
+    /// # Arguments
+    /// * `value` - Value to encode.
+    pub(in crate::osm_pbf) fn zigzag(value: std::primitive::i64) -> std::primitive::u64 {
+        ((value << 1) ^ (value >> 63)) as std::primitive::u64
+    }
+
+    /// Encode length delimited field.
+    ///
+    /// # Arguments
+    /// * `number` - Field number.
+    /// * `bytes` - Value of the field.
+    pub(in crate::osm_pbf) fn bytes_field(
+        number: std::primitive::u64,
+        bytes: &[std::primitive::u8],
+    ) -> std::vec::Vec<std::primitive::u8> {
+        let mut field: std::vec::Vec<std::primitive::u8> = varint(number << 3 | 2);
+        field.extend(varint(bytes.len() as std::primitive::u64));
+        field.extend(bytes);
+        field
+    }
+
+    /// Encode packed varint field.
+    ///
+    /// # Arguments
+    /// * `number` - Field number.
+    /// * `values` - Values of the field.
+    pub(in crate::osm_pbf) fn packed_field(
+        number: std::primitive::u64,
+        values: &[std::primitive::u64],
+    ) -> std::vec::Vec<std::primitive::u8> {
+        bytes_field(
+            number,
+            &values
+                .iter()
+                .flat_map(|value| varint(*value))
+                .collect::<std::vec::Vec<std::primitive::u8>>(),
+        )
+    }
+
+    /// Encode block with string table and one group.
+    ///
+    /// # Arguments
+    /// * `strings` - String table, first string is empty by the format.
+    /// * `group` - PrimitiveGroup message.
+    pub(in crate::osm_pbf) fn block(
+        strings: &[&std::primitive::str],
+        group: &[std::primitive::u8],
+    ) -> std::vec::Vec<std::primitive::u8> {
+        let mut string_table: std::vec::Vec<std::primitive::u8> =
+            std::vec::Vec::<std::primitive::u8>::new();
+        for string in strings {
+            string_table.extend(bytes_field(1, string.as_bytes()));
+        }
+        let mut block: std::vec::Vec<std::primitive::u8> = bytes_field(1, &string_table);
+        block.extend(bytes_field(2, group));
+        block
+    }
+
+    /// Encode group of dense nodes.
+    ///
+    /// # Arguments
+    /// * `ids` - Node ids.
+    /// * `lats` - Latitudes in granularity units.
+    /// * `lons` - Longitudes in granularity units.
+    /// * `keys_values` - String indexes of keys and values, nodes separated by zero.
+    pub(in crate::osm_pbf) fn dense_group(
+        ids: &[std::primitive::i64],
+        lats: &[std::primitive::i64],
+        lons: &[std::primitive::i64],
+        keys_values: &[std::primitive::u64],
+    ) -> std::vec::Vec<std::primitive::u8> {
+        let deltas = |values: &[std::primitive::i64]| -> std::vec::Vec<std::primitive::u64> {
+            let mut previous: std::primitive::i64 = 0;
+            values
+                .iter()
+                .map(|value| {
+                    let delta: std::primitive::u64 = zigzag(value.wrapping_sub(previous));
+                    previous = *value;
+                    delta
+                })
+                .collect()
+        };
+        let mut dense: std::vec::Vec<std::primitive::u8> = packed_field(1, &deltas(ids));
+        dense.extend(packed_field(8, &deltas(lats)));
+        dense.extend(packed_field(9, &deltas(lons)));
+        dense.extend(packed_field(10, keys_values));
+        bytes_field(2, &dense)
+    }
+
+    /// Node as id, latitude, longitude and tags.
+    type Node = (
+        std::primitive::i64,
+        std::primitive::f64,
+        std::primitive::f64,
+        std::vec::Vec<(std::string::String, std::string::String)>,
+    );
+
+    /// Way as tags and node references.
+    type Way = (
+        std::vec::Vec<(std::string::String, std::string::String)>,
+        std::vec::Vec<std::primitive::i64>,
+    );
+
+    /// Nodes of the block as id, latitude, longitude and tags.
+    ///
+    /// # Arguments
+    /// * `data` - PrimitiveBlock message.
+    fn nodes(
+        data: &[std::primitive::u8],
+    ) -> std::result::Result<std::vec::Vec<Node>, std::io::Error> {
+        let mut nodes: std::vec::Vec<Node> = std::vec::Vec::<Node>::new();
+        super::PrimitiveBlock::parse(data)?.nodes(|id, lat, lon, tags| {
+            nodes.push((
+                id,
+                lat,
+                lon,
+                tags.iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect(),
+            ))
+        })?;
+        Ok(nodes)
+    }
+
+    #[test]
+    fn decodes_dense_nodes() {
+        let nodes: std::vec::Vec<Node> = nodes(&block(
+            &["", "shop", "supermarket", "name"],
+            &dense_group(
+                &[10, 12, 11],
+                &[615_000_000, 615_000_100, 614_999_900],
+                &[238_000_000, 237_999_000, 238_000_500],
+                &[1, 2, 0, 0, 3, 1, 0],
+            ),
+        ))
+        .unwrap();
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(nodes[0].0, 10);
+        assert!((nodes[0].1 - 61.5).abs() < 0.000001);
+        assert!((nodes[0].2 - 23.8).abs() < 0.000001);
+        assert_eq!(
+            nodes[0].3,
+            std::vec![("shop".to_string(), "supermarket".to_string())]
+        );
+        assert_eq!(nodes[1].0, 12);
+        assert!((nodes[1].1 - 61.50001).abs() < 0.000001);
+        assert!(nodes[1].3.is_empty());
+        assert_eq!(nodes[2].0, 11);
+        assert!((nodes[2].2 - 23.80005).abs() < 0.000001);
+        assert_eq!(
+            nodes[2].3,
+            std::vec![("name".to_string(), "shop".to_string())]
+        );
+    }
+
+    #[test]
+    fn rejects_overflowing_deltas() {
+        let result: std::result::Result<std::vec::Vec<Node>, std::io::Error> = nodes(&block(
+            &[""],
+            &dense_group(
+                &[std::primitive::i64::MAX, std::primitive::i64::MIN],
+                &[0, 0],
+                &[0, 0],
+                &[],
+            ),
+        ));
+        // Delta from MAX to MIN wraps to 1 when encoded, so decoding it overflows.
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rejects_overflowing_way_node_ids() {
+        let mut way: std::vec::Vec<std::primitive::u8> = std::vec::Vec::<std::primitive::u8>::new();
+        way.extend(packed_field(
+            8,
+            &[zigzag(std::primitive::i64::MAX), zigzag(1)],
+        ));
+        let data: std::vec::Vec<std::primitive::u8> = block(&[""], &bytes_field(3, &way));
+        let block: super::PrimitiveBlock<'_> = super::PrimitiveBlock::parse(&data).unwrap();
+        assert!(block.ways(|_, _| {}).is_err());
+    }
+
+    #[test]
+    fn decodes_plain_nodes_with_offsets() {
+        let mut node: std::vec::Vec<std::primitive::u8> = varint(1 << 3);
+        node.extend(varint(zigzag(-5)));
+        node.extend(packed_field(2, &[1]));
+        node.extend(packed_field(3, &[2]));
+        node.extend(varint(8 << 3));
+        node.extend(varint(zigzag(100)));
+        node.extend(varint(9 << 3));
+        node.extend(varint(zigzag(-100)));
+        let mut data: std::vec::Vec<std::primitive::u8> =
+            block(&["", "amenity", "school"], &bytes_field(1, &node));
+        // Granularity of 1000 nanodegrees and latitude offset of 1 degree.
+        data.extend(varint(17 << 3));
+        data.extend(varint(1000));
+        data.extend(varint(19 << 3));
+        data.extend(varint(1_000_000_000));
+        let nodes: std::vec::Vec<Node> = nodes(&data).unwrap();
+        assert_eq!(nodes.len(), 1);
+        assert_eq!(nodes[0].0, -5);
+        assert!((nodes[0].1 - 1.0001).abs() < 0.0000001);
+        assert!((nodes[0].2 + 0.0001).abs() < 0.0000001);
+        assert_eq!(
+            nodes[0].3,
+            std::vec![("amenity".to_string(), "school".to_string())]
+        );
+    }
+
+    #[test]
+    fn decodes_ways() {
+        let mut way: std::vec::Vec<std::primitive::u8> = packed_field(2, &[1]);
+        way.extend(packed_field(3, &[2]));
+        way.extend(packed_field(8, &[zigzag(10), zigzag(5), zigzag(-3)]));
+        let data: std::vec::Vec<std::primitive::u8> =
+            block(&["", "shop", "convenience"], &bytes_field(3, &way));
+        let block: super::PrimitiveBlock<'_> = super::PrimitiveBlock::parse(&data).unwrap();
+        let mut ways: std::vec::Vec<Way> = std::vec::Vec::new();
+        block
+            .ways(|tags, refs| {
+                ways.push((
+                    tags.iter()
+                        .map(|(key, value)| (key.to_string(), value.to_string()))
+                        .collect(),
+                    refs,
+                ))
+            })
+            .unwrap();
+        assert_eq!(
+            ways,
+            std::vec![(
+                std::vec![("shop".to_string(), "convenience".to_string())],
+                std::vec![10, 15, 12]
+            )]
+        );
+        // Ways are not nodes.
+        assert!(nodes(&data).unwrap().is_empty());
+    }
+
+    #[test]
+    fn invalid_strings_and_indexes_are_empty() {
+        let mut string_table: std::vec::Vec<std::primitive::u8> = bytes_field(1, b"");
+        string_table.extend(bytes_field(1, &[0xff, 0xfe]));
+        let mut data: std::vec::Vec<std::primitive::u8> = bytes_field(1, &string_table);
+        data.extend(bytes_field(2, &dense_group(&[1], &[0], &[0], &[1, 9, 0])));
+        let nodes: std::vec::Vec<Node> = nodes(&data).unwrap();
+        assert_eq!(
+            nodes[0].3,
+            std::vec![(std::string::String::new(), std::string::String::new())]
+        );
+    }
+}
@@ -0,0 +1,8 @@
+mod osrm;
+mod response;
+mod route;
+
+pub(crate) use self::osrm::Osrm;
+
+use self::response::Response;
+use self::route::Route;
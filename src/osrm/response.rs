@@ -0,0 +1,5 @@
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Response {
+    pub(super) routes: std::vec::Vec<super::Route>,
+}
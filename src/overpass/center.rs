@@ -0,0 +1,6 @@
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Center {
+    pub(super) lat: std::primitive::f64,
+    pub(super) lon: std::primitive::f64,
+}
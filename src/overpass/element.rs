@@ -0,0 +1,28 @@
+/// OpenStreetMap node, way or relation.
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Element {
+    /// Latitude, only for nodes.
+    pub(super) lat: std::option::Option<std::primitive::f64>,
+    /// Longitude, only for nodes.
+    pub(super) lon: std::option::Option<std::primitive::f64>,
+    /// Center, only for ways and relations.
+    pub(super) center: std::option::Option<super::Center>,
+    #[serde(default)]
+    pub(super) tags: std::collections::HashMap<std::string::String, std::string::String>,
+}
+
+impl Element {
+    /// Location of the element.
+    pub(super) fn location(&self) -> std::option::Option<longitude::Location> {
+        if let Some(lat) = self.lat {
+            if let Some(lon) = self.lon {
+                return Some(longitude::Location::from(lat, lon));
+            }
+        }
+        if let Some(center) = &self.center {
+            return Some(longitude::Location::from(center.lat, center.lon));
+        }
+        None
+    }
+}
@@ -0,0 +1,10 @@
+mod center;
+mod element;
+mod overpass;
+mod response;
+
+pub(crate) use self::overpass::Overpass;
+
+use self::center::Center;
+use self::element::Element;
+use self::response::Response;
@@ -0,0 +1,131 @@
+/// Overpass API client for OpenStreetMap data.
+pub(crate) struct Overpass {
+    base_url: std::string::String,
+    limiter: std::option::Option<std::sync::Arc<tokio::sync::Mutex<crate::client::BetweenCalls>>>,
+    radius_meters: std::primitive::u32,
+}
+
+/// URL for the public Overpass API.
+const API_URL: &std::primitive::str = "https://overpass-api.de/api/interpreter";
+
+static LIMITER: once_cell::sync::Lazy<
+    std::sync::Arc<tokio::sync::Mutex<crate::client::BetweenCalls>>,
+> = once_cell::sync::Lazy::new(|| {
+    std::sync::Arc::new(tokio::sync::Mutex::new(crate::client::BetweenCalls::new(
+        5000,
+    )))
+});
+
+impl Overpass {
+    /// Create new Overpass API client.
+    /// Public API is rate limited, self-hosted instance given with `base_url` is not.
+    ///
+    /// # Arguments
+    /// * `base_url` - Optional interpreter URL for self-hosted instance, for example: http://localhost:12345/api/interpreter
+    /// * `radius_km` - Radius in kilometers to search amenities from.
+    pub(crate) fn new(
+        base_url: std::option::Option<&std::primitive::str>,
+        radius_km: std::primitive::f64,
+    ) -> Self {
+        Self {
+            base_url: base_url.unwrap_or(API_URL).to_string(),
+            limiter: match base_url {
+                Some(_) => None,
+                None => Some(std::sync::Arc::clone(&LIMITER)),
+            },
+            radius_meters: (radius_km * 1000.0).ceil() as std::primitive::u32,
+        }
+    }
+
+    /// Overpass QL tag filters for the amenity.
+    ///
+    /// # Arguments
+    /// * `amenity` - Amenity.
+    pub(self) fn filters(amenity: crate::app::Amenity) -> &'static [&'static std::primitive::str] {
+        match amenity {
+            crate::app::Amenity::Grocery => &[r#"["shop"~"^(supermarket|convenience)$"]"#],
+            crate::app::Amenity::School => &[r#"["amenity"="school"]"#],
+            crate::app::Amenity::Daycare => &[r#"["amenity"="kindergarten"]"#],
+            crate::app::Amenity::TrainStation => {
+                &[r#"["railway"~"^(station|halt)$"]["station"!="subway"]"#]
+            }
+            crate::app::Amenity::HealthCentre => {
+                &[r#"["healthcare"="centre"]"#, r#"["amenity"="clinic"]"#]
+            }
+        }
+    }
+
+    /// Overpass QL query for all amenities around location.
+    ///
+    /// # Arguments
+    /// * `location` - Location to search around.
+    pub(self) fn query(&self, location: &longitude::Location) -> std::string::String {
+        let mut query: std::string::String = "[out:json][timeout:60];(".to_string();
+        for amenity in crate::app::Amenity::ALL {
+            for filter in Self::filters(amenity) {
+                query.push_str(&format!(
+                    "nwr(around:{},{},{}){};",
+                    self.radius_meters, location.latitude, location.longitude, filter
+                ));
+            }
+        }
+        query.push_str(");out center tags;");
+        query
+    }
+
+    /// Straight line distances in kilometers to nearest amenities.
+    /// Amenities not found within the radius are missing from the map.
+    ///
+    /// # Arguments
+    /// * `location` - Location to search around.
+    pub(crate) async fn nearest_km(
+        &self,
+        location: longitude::Location,
+    ) -> std::result::Result<crate::app::AmenityKm, crate::client::JSONError> {
+        let mut nearest_km: crate::app::AmenityKm = std::collections::HashMap::new();
+        for element in crate::client::Client::new(
+            // Always caching amenities, because they should not change often.
+            Some("overpass/interpreter"),
+            self.limiter.clone(),
+        )?
+        .get_json::<super::Response>(
+            url::Url::parse_with_params(&self.base_url, &[("data", self.query(&location))])
+                .map_err(crate::client::RequestError::from)?
+                .as_str(),
+            None,
+        )
+        .await?
+        .elements
+        {
+            if let Some(element_location) = element.location() {
+                let km: std::primitive::f64 = element_location.distance(&location).kilometers();
+                for amenity in crate::app::Amenity::ALL {
+                    if amenity.matches(&element.tags) {
+                        let _: &mut std::primitive::f64 = nearest_km
+                            .entry(amenity)
+                            .and_modify(|nearest| *nearest = nearest.min(km))
+                            .or_insert(km);
+                    }
+                }
+            }
+        }
+        Ok(nearest_km)
+    }
+}
+
+impl crate::app::Amenities for Overpass {
+    /// Straight line distances in kilometers to nearest amenities.
+    fn nearest_km<'a>(
+        &'a self,
+        location: longitude::Location,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<
+                    Output = std::result::Result<crate::app::AmenityKm, crate::client::JSONError>,
+                > + Send
+                + 'a,
+        >,
+    > {
+        std::boxed::Box::pin(Overpass::nearest_km(self, location))
+    }
+}
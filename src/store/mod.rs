@@ -0,0 +1,3 @@
+mod store;
+
+pub(crate) use self::store::Store;
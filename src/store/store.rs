@@ -0,0 +1,52 @@
+/// JSON file based storage for local data that must persist between runs.
+#[derive(Debug, Clone)]
+pub(crate) struct Store {
+    pub(self) path: std::string::String,
+}
+
+impl Store {
+    /// Create a new store.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the JSON file in data directory.
+    pub(crate) fn new(name: &std::primitive::str) -> std::result::Result<Self, std::io::Error> {
+        let mut exe_dir: std::path::PathBuf = std::env::current_exe()?;
+        let _: bool = exe_dir.pop(); // Remove the executable name to get the directory
+        let dir: std::path::PathBuf = exe_dir.join("data");
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self {
+            path: dir
+                .join(format!("{}.json", name))
+                .to_str()
+                .ok_or(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "Could not convert store path to string",
+                ))?
+                .to_string(),
+        })
+    }
+
+    /// Load data from the store, default if nothing is stored yet.
+    pub(crate) fn load<T: serde::de::DeserializeOwned + std::default::Default>(
+        &self,
+    ) -> std::result::Result<T, std::io::Error> {
+        if !std::path::Path::new(&self.path).exists() {
+            return Ok(T::default());
+        }
+        Ok(serde_json::from_str(&std::fs::read_to_string(&self.path)?)?)
+    }
+
+    /// Save data to the store.
+    ///
+    /// # Arguments
+    /// * `data` - Data to save.
+    pub(crate) fn save<T: serde::Serialize>(
+        &self,
+        data: &T,
+    ) -> std::result::Result<(), std::io::Error> {
+        // Write to temporary file first, so that interrupted write does not lose stored data.
+        let path_tmp: std::string::String = format!("{}.tmp", self.path);
+        std::fs::write(&path_tmp, serde_json::to_string_pretty(data)?)?;
+        std::fs::rename(path_tmp, &self.path)
+    }
+}
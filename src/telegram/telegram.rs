@@ -2,7 +2,9 @@
 #[derive(Clone)]
 pub(crate) struct Telegram {
     bot: teloxide::prelude::Bot,
-    user_id: teloxide::prelude::UserId,
+    chat_ids: std::vec::Vec<teloxide::types::ChatId>,
+    digest: std::primitive::bool,
+    digest_top: std::primitive::usize,
 }
 
 impl Telegram {
@@ -10,22 +12,490 @@ impl Telegram {
     ///
     /// # Arguments
     /// * `token` - Telegram bot token.
-    /// * `user_id` - Telegram user id to send messages to.
-    pub(crate) fn new(token: &std::primitive::str, user_id: std::primitive::u64) -> Self {
+    /// * `chat_ids` - Telegram user or group chat ids to send messages to.
+    /// * `digest` - Send digest of top houses instead of a message per house.
+    /// * `digest_top` - Number of top houses in the digest.
+    pub(crate) fn new(
+        token: &std::primitive::str,
+        chat_ids: std::vec::Vec<std::primitive::i64>,
+        digest: std::primitive::bool,
+        digest_top: std::primitive::usize,
+    ) -> Self {
         Telegram {
             bot: teloxide::prelude::Bot::new(token),
-            user_id: teloxide::prelude::UserId(user_id),
+            chat_ids: chat_ids.into_iter().map(teloxide::types::ChatId).collect(),
+            digest,
+            digest_top,
         }
     }
 
-    /// Sends a message to user who's id was used in initialization.
+    /// Sends a message to every chat who's id was used in initialization.
+    /// Message that is too long is split into multiple messages.
     ///
     /// # Arguments
     /// * `message` - Message to send.
     pub(crate) async fn send_message(
         &self,
         message: &std::primitive::str,
-    ) -> std::result::Result<teloxide::prelude::Message, teloxide::RequestError> {
-        teloxide::requests::Requester::send_message(&self.bot, self.user_id, message).await
+    ) -> std::result::Result<(), teloxide::RequestError> {
+        for chat_id in &self.chat_ids {
+            for part in Self::split(message, false) {
+                let _: teloxide::prelude::Message = Self::send(
+                    *chat_id,
+                    teloxide::requests::Requester::send_message(&self.bot, *chat_id, part),
+                )
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sends a HTML formatted message to every chat who's id was used in initialization.
+    /// Message that is too long is split into multiple messages.
+    ///
+    /// # Arguments
+    /// * `html` - HTML formatted message to send.
+    pub(crate) async fn send_html(
+        &self,
+        html: &std::primitive::str,
+    ) -> std::result::Result<(), teloxide::RequestError> {
+        for chat_id in &self.chat_ids {
+            for part in Self::split(html, true) {
+                let _: teloxide::prelude::Message = Self::send(
+                    *chat_id,
+                    teloxide::payloads::SendMessageSetters::parse_mode(
+                        teloxide::requests::Requester::send_message(&self.bot, *chat_id, part),
+                        teloxide::types::ParseMode::Html,
+                    ),
+                )
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sends a house to every chat who's id was used in initialization.
+    /// Sends photo first, then HTML formatted message with inline keyboard and last venue that opens in map app.
+    /// Failing to send photo does not fail, because Telegram may not be able to fetch it.
+    ///
+    /// # Arguments
+    /// * `html` - HTML formatted message.
+    /// * `photo_url` - Optional URL for the photo.
+    /// * `venue` - Optional location and address for the venue.
+    /// * `keyboard` - Inline keyboard under the message.
+    pub(crate) async fn send_house(
+        &self,
+        html: &std::primitive::str,
+        photo_url: std::option::Option<&std::primitive::str>,
+        venue: std::option::Option<(longitude::Location, &std::primitive::str)>,
+        keyboard: teloxide::types::InlineKeyboardMarkup,
+    ) -> std::result::Result<(), teloxide::RequestError> {
+        let photo: std::option::Option<teloxide::types::InputFile> =
+            photo_url.and_then(|photo_url| match url::Url::parse(photo_url) {
+                Ok(photo_url) => Some(teloxide::types::InputFile::url(photo_url)),
+                Err(error) => {
+                    eprintln!("Invalid photo URL {}: {}", photo_url, error);
+                    None
+                }
+            });
+        let parts: std::vec::Vec<std::string::String> = Self::split(html, true);
+        for chat_id in &self.chat_ids {
+            if let Some(photo) = &photo {
+                if let Err(error) = Self::send(
+                    *chat_id,
+                    teloxide::requests::Requester::send_photo(&self.bot, *chat_id, photo.clone()),
+                )
+                .await
+                {
+                    eprintln!("Failed to send photo: {}", error);
+                }
+            }
+            for (index, part) in parts.iter().enumerate() {
+                let request: teloxide::requests::JsonRequest<teloxide::payloads::SendMessage> =
+                    teloxide::payloads::SendMessageSetters::parse_mode(
+                        teloxide::requests::Requester::send_message(&self.bot, *chat_id, part),
+                        teloxide::types::ParseMode::Html,
+                    );
+                let _: teloxide::prelude::Message = if index + 1 == parts.len() {
+                    // Keyboard under the last part, so it is under the whole house.
+                    Self::send(
+                        *chat_id,
+                        teloxide::payloads::SendMessageSetters::reply_markup(
+                            request,
+                            keyboard.clone(),
+                        ),
+                    )
+                    .await?
+                } else {
+                    Self::send(*chat_id, request).await?
+                };
+            }
+            if let Some((location, address)) = &venue {
+                let _: teloxide::prelude::Message = Self::send(
+                    *chat_id,
+                    teloxide::requests::Requester::send_venue(
+                        &self.bot,
+                        *chat_id,
+                        location.latitude,
+                        location.longitude,
+                        *address,
+                        *address,
+                    ),
+                )
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sends a file as document to every chat who's id was used in initialization.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the file.
+    /// * `caption` - Caption for the document.
+    pub(crate) async fn send_document(
+        &self,
+        path: &std::primitive::str,
+        caption: &std::primitive::str,
+    ) -> std::result::Result<(), teloxide::RequestError> {
+        for chat_id in &self.chat_ids {
+            let _: teloxide::prelude::Message = Self::send(
+                *chat_id,
+                teloxide::payloads::SendDocumentSetters::caption(
+                    teloxide::requests::Requester::send_document(
+                        &self.bot,
+                        *chat_id,
+                        teloxide::types::InputFile::file(path),
+                    ),
+                    caption,
+                ),
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Send request to the chat respecting rate limits.
+    /// Retries if Telegram asks to retry after a while.
+    ///
+    /// # Arguments
+    /// * `chat_id` - Telegram chat id that the request is sent to.
+    /// * `request` - Request to send.
+    pub(self) async fn send<R: teloxide::requests::Request<Err = teloxide::RequestError>>(
+        chat_id: teloxide::types::ChatId,
+        request: R,
+    ) -> std::result::Result<
+        <<R as teloxide::requests::HasPayload>::Payload as teloxide::requests::Payload>::Output,
+        teloxide::RequestError,
+    > {
+        let mut retries: std::primitive::u8 = 0;
+        loop {
+            crate::client::Limiter::limit(&mut *GLOBAL_LIMITER.lock().await).await;
+            let limiter: std::sync::Arc<tokio::sync::Mutex<crate::client::BetweenCalls>> =
+                std::sync::Arc::clone(CHAT_LIMITERS.lock().await.entry(chat_id).or_insert_with(
+                    || {
+                        std::sync::Arc::new(tokio::sync::Mutex::new(
+                            crate::client::BetweenCalls::new(if chat_id.is_group() {
+                                GROUP_MS_BETWEEN_MESSAGES
+                            } else {
+                                CHAT_MS_BETWEEN_MESSAGES
+                            }),
+                        ))
+                    },
+                ));
+            crate::client::Limiter::limit(&mut *limiter.lock().await).await;
+            match teloxide::requests::Request::send_ref(&request).await {
+                Err(teloxide::RequestError::RetryAfter(seconds)) if retries < MAX_RETRIES => {
+                    retries += 1;
+                    eprintln!(
+                        "Telegram asked to retry after {} seconds, retry {}/{}.",
+                        seconds.seconds(),
+                        retries,
+                        MAX_RETRIES
+                    );
+                    tokio::time::sleep(seconds.duration()).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Split message into parts that fit into a single Telegram message.
+    /// Splits between lines, so that HTML tags of a line stay in the same part.
+    ///
+    /// # Arguments
+    /// * `message` - Message to split.
+    /// * `html` - Is the message HTML formatted?
+    pub(self) fn split(
+        message: &std::primitive::str,
+        html: std::primitive::bool,
+    ) -> std::vec::Vec<std::string::String> {
+        let mut parts: std::vec::Vec<std::string::String> =
+            std::vec::Vec::<std::string::String>::new();
+        let mut part: std::string::String = std::string::String::new();
+        for line in message.split('\n') {
+            let mut line: std::string::String = line.to_string();
+            // Line that does not fit into a message by itself is split by itself.
+            if line.chars().count() > MAX_MESSAGE_LENGTH {
+                if !part.is_empty() {
+                    parts.push(std::mem::take(&mut part));
+                }
+                let mut line_parts: std::vec::Vec<std::string::String> = if html {
+                    Self::split_html_line(&line)
+                } else {
+                    Self::split_line(&line)
+                };
+                line = line_parts.pop().unwrap_or_default();
+                parts.extend(line_parts);
+            }
+            if !part.is_empty()
+                && part.chars().count() + 1 + line.chars().count() > MAX_MESSAGE_LENGTH
+            {
+                parts.push(std::mem::take(&mut part));
+            }
+            if !part.is_empty() {
+                part.push('\n');
+            }
+            part.push_str(&line);
+        }
+        if !part.is_empty() || parts.is_empty() {
+            parts.push(part);
+        }
+        parts
+    }
+
+    /// Split plain text line from the character limit.
+    ///
+    /// # Arguments
+    /// * `line` - Line to split.
+    pub(self) fn split_line(line: &std::primitive::str) -> std::vec::Vec<std::string::String> {
+        line.chars()
+            .collect::<std::vec::Vec<std::primitive::char>>()
+            .chunks(MAX_MESSAGE_LENGTH)
+            .map(|chunk| chunk.iter().collect())
+            .collect()
+    }
+
+    /// Split HTML formatted line so that tags and entities are not cut.
+    /// Tags that are open at the split are closed at the end of the part and opened again in the next one.
+    ///
+    /// # Arguments
+    /// * `line` - HTML formatted line to split.
+    pub(self) fn split_html_line(line: &std::primitive::str) -> std::vec::Vec<std::string::String> {
+        let mut parts: std::vec::Vec<std::string::String> =
+            std::vec::Vec::<std::string::String>::new();
+        let mut part: std::string::String = std::string::String::new();
+        // Opening tags and names of the tags that are open.
+        let mut open: std::vec::Vec<(std::string::String, std::string::String)> =
+            std::vec::Vec::<(std::string::String, std::string::String)>::new();
+        let mut rest: &std::primitive::str = line;
+        while let Some(first) = rest.chars().next() {
+            // Tag or entity is kept whole, if it is terminated.
+            let length: std::primitive::usize = match first {
+                '<' => rest.find('>').map(|end| end + 1),
+                '&' => rest.find(';').filter(|end| {
+                    rest[1..*end]
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '#')
+                }),
+                _ => None,
+            }
+            .unwrap_or(first.len_utf8());
+            let piece: &std::primitive::str = &rest[..length];
+            rest = &rest[length..];
+
+            let closing: std::string::String = open
+                .iter()
+                .rev()
+                .map(|(_, name)| format!("</{}>", name))
+                .collect();
+            if !part.is_empty()
+                && part.chars().count() + piece.chars().count() + closing.chars().count()
+                    > MAX_MESSAGE_LENGTH
+            {
+                part.push_str(&closing);
+                parts.push(std::mem::take(&mut part));
+                for (tag, _) in &open {
+                    part.push_str(tag);
+                }
+            }
+            part.push_str(piece);
+
+            if let Some(closing) = piece.strip_prefix("</") {
+                let name: std::string::String = Self::tag_name(closing);
+                if let Some(index) = open.iter().rposition(|(_, open_name)| *open_name == name) {
+                    open.truncate(index);
+                }
+            } else if piece.starts_with('<') && piece.ends_with('>') && piece.len() > 2 {
+                open.push((piece.to_string(), Self::tag_name(&piece[1..])));
+            }
+        }
+        if !part.is_empty() {
+            parts.push(part);
+        }
+        parts
+    }
+
+    /// Name of the HTML tag.
+    ///
+    /// # Arguments
+    /// * `tag` - Tag after it's opening characters, for example `a href="...">`.
+    pub(self) fn tag_name(tag: &std::primitive::str) -> std::string::String {
+        tag.chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect::<std::string::String>()
+            .to_lowercase()
+    }
+}
+
+impl crate::app::Notifier for Telegram {
+    /// Send alerts, every house or digest of them and summary.
+    fn notify<'a>(
+        &'a self,
+        notification: &'a crate::app::Notification,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<Output = std::result::Result<(), crate::app::Error>>
+                + Send
+                + 'a,
+        >,
+    > {
+        std::boxed::Box::pin(async move {
+            for alert in &notification.alerts {
+                self.send_message(&alert.message).await?;
+            }
+            if self.digest || notification.digest {
+                let mut digest: std::string::String = format!(
+                    "<b>Top {} of {} houses:</b>",
+                    self.digest_top.min(notification.houses.len()),
+                    notification.houses.len()
+                );
+                for (index, house) in notification.houses.iter().take(self.digest_top).enumerate() {
+                    digest.push_str(&format!("\n{}. {}", index + 1, house.summary_html));
+                }
+                if !notification.houses.is_empty() {
+                    self.send_html(&digest).await?;
+                }
+                if let Some(csv_path) = &notification.csv_path {
+                    self.send_document(
+                        csv_path,
+                        &format!("All {} houses.", notification.houses.len()),
+                    )
+                    .await?;
+                }
+            } else {
+                for house in &notification.houses {
+                    self.send_house(
+                        &house.message_html,
+                        house.photo_url.as_deref(),
+                        house
+                            .location
+                            .clone()
+                            .map(|location| (location, house.address.as_str())),
+                        crate::app::ListingAction::keyboard(&house.url),
+                    )
+                    .await?;
+                }
+            }
+            self.send_message(&notification.summary).await?;
+            Ok(())
+        })
+    }
+
+    /// Send plain text message.
+    fn send_message<'a>(
+        &'a self,
+        message: &'a std::primitive::str,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<Output = std::result::Result<(), crate::app::Error>>
+                + Send
+                + 'a,
+        >,
+    > {
+        std::boxed::Box::pin(async move { Ok(Telegram::send_message(self, message).await?) })
+    }
+}
+
+/// Maximum length of a Telegram message in characters.
+const MAX_MESSAGE_LENGTH: std::primitive::usize = 4096;
+
+/// Maximum number of retries when Telegram asks to retry after a while.
+const MAX_RETRIES: std::primitive::u8 = 5;
+
+// Telegram allows about one message per second to a chat.
+const CHAT_MS_BETWEEN_MESSAGES: std::primitive::u16 = 1000;
+
+// Telegram allows 20 messages per minute to a group chat.
+const GROUP_MS_BETWEEN_MESSAGES: std::primitive::u16 = 3000;
+
+// Telegram allows 30 messages per second in total.
+static GLOBAL_LIMITER: once_cell::sync::Lazy<tokio::sync::Mutex<crate::client::BetweenCalls>> =
+    once_cell::sync::Lazy::new(|| tokio::sync::Mutex::new(crate::client::BetweenCalls::new(35)));
+
+/// Limiter for every chat that messages have been sent to.
+static CHAT_LIMITERS: once_cell::sync::Lazy<
+    tokio::sync::Mutex<
+        std::collections::HashMap<
+            teloxide::types::ChatId,
+            std::sync::Arc<tokio::sync::Mutex<crate::client::BetweenCalls>>,
+        >,
+    >,
+> = once_cell::sync::Lazy::new(|| tokio::sync::Mutex::new(std::collections::HashMap::new()));
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn splits_plain_text_from_character_limit() {
+        let line: std::string::String = "ä".repeat(super::MAX_MESSAGE_LENGTH + 10);
+        let parts: std::vec::Vec<std::string::String> = super::Telegram::split(&line, false);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].chars().count(), super::MAX_MESSAGE_LENGTH);
+        assert_eq!(parts[1].chars().count(), 10);
+    }
+
+    #[test]
+    fn does_not_cut_entities() {
+        let line: std::string::String =
+            format!("{}&amp;&amp;", "a".repeat(super::MAX_MESSAGE_LENGTH - 6));
+        let parts: std::vec::Vec<std::string::String> = super::Telegram::split(&line, true);
+        assert_eq!(
+            parts,
+            std::vec![
+                format!("{}&amp;", "a".repeat(super::MAX_MESSAGE_LENGTH - 6)),
+                "&amp;".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn closes_and_reopens_tags() {
+        let line: std::string::String = format!(
+            "<b><a href=\"https://example.com\">{}</a></b>",
+            "a".repeat(super::MAX_MESSAGE_LENGTH)
+        );
+        let parts: std::vec::Vec<std::string::String> = super::Telegram::split(&line, true);
+        assert_eq!(parts.len(), 2);
+        for part in &parts {
+            assert!(part.chars().count() <= super::MAX_MESSAGE_LENGTH);
+            assert!(part.starts_with("<b><a href=\"https://example.com\">"));
+            assert!(part.ends_with("</a></b>"));
+        }
+        assert_eq!(
+            parts
+                .iter()
+                .map(|part| part.matches('a').count())
+                .sum::<std::primitive::usize>(),
+            // Opening and closing tags of both parts contain three more.
+            super::MAX_MESSAGE_LENGTH + 2 * 3
+        );
+    }
+
+    #[test]
+    fn keeps_lines_together() {
+        assert_eq!(
+            super::Telegram::split("<b>a</b>\nb", true),
+            std::vec!["<b>a</b>\nb".to_string()]
+        );
     }
 }
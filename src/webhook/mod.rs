@@ -0,0 +1,5 @@
+mod webhook;
+mod webhook_kind;
+
+pub(crate) use self::webhook::Webhook;
+pub(crate) use self::webhook_kind::WebhookKind;
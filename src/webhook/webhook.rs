@@ -0,0 +1,91 @@
+/// Webhook that results are posted to as JSON.
+#[derive(Debug, Clone)]
+pub(crate) struct Webhook {
+    pub(self) url: std::string::String,
+    pub(self) kind: super::WebhookKind,
+}
+
+// Discord allows 5 requests per 2 seconds for a webhook, so being conservative.
+static LIMITER: once_cell::sync::Lazy<
+    std::sync::Arc<tokio::sync::Mutex<crate::client::BetweenCalls>>,
+> = once_cell::sync::Lazy::new(|| {
+    std::sync::Arc::new(tokio::sync::Mutex::new(crate::client::BetweenCalls::new(
+        1000,
+    )))
+});
+
+impl Webhook {
+    /// Create new webhook.
+    ///
+    /// # Arguments
+    /// * `url` - URL of the webhook.
+    /// * `kind` - Kind of the webhook.
+    pub(crate) fn new(url: &std::primitive::str, kind: super::WebhookKind) -> Self {
+        Self {
+            url: url.to_string(),
+            kind,
+        }
+    }
+
+    /// Post event to the webhook.
+    ///
+    /// # Arguments
+    /// * `event` - Event type: message, alert, house or summary.
+    /// * `message` - Plain text message.
+    /// * `house` - Fields of the house for house event.
+    pub(self) async fn post(
+        &self,
+        event: &std::primitive::str,
+        message: &std::primitive::str,
+        house: std::option::Option<&serde_json::Value>,
+    ) -> std::result::Result<(), crate::client::RequestError> {
+        return crate::client::Client::new(None, Some(std::sync::Arc::clone(&LIMITER)))?
+            .send_json(
+                reqwest::Method::POST,
+                &self.url,
+                self.kind.payload(event, message, house),
+                None,
+            )
+            .await;
+    }
+}
+
+impl crate::app::Notifier for Webhook {
+    /// Post every alert, house and summary.
+    fn notify<'a>(
+        &'a self,
+        notification: &'a crate::app::Notification,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<Output = std::result::Result<(), crate::app::Error>>
+                + Send
+                + 'a,
+        >,
+    > {
+        std::boxed::Box::pin(async move {
+            for alert in &notification.alerts {
+                self.post("alert", &alert.message, None).await?;
+            }
+            for house in &notification.houses {
+                self.post("house", &house.message, Some(&house.json))
+                    .await?;
+            }
+            self.post("summary", &notification.summary, None).await?;
+            Ok(())
+        })
+    }
+
+    /// Post plain text message.
+    fn send_message<'a>(
+        &'a self,
+        message: &'a std::primitive::str,
+    ) -> std::pin::Pin<
+        std::boxed::Box<
+            dyn std::future::Future<Output = std::result::Result<(), crate::app::Error>>
+                + Send
+                + 'a,
+        >,
+    > {
+        std::boxed::Box::pin(async move { Ok(self.post("message", message, None).await?) })
+    }
+}
@@ -0,0 +1,43 @@
+/// Kind of the webhook, that defines the payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WebhookKind {
+    /// Generic webhook that gets event, message and house fields.
+    Json,
+    /// Discord incoming webhook.
+    Discord,
+    /// Slack compatible incoming webhook.
+    Slack,
+}
+
+impl WebhookKind {
+    /// Payload for the webhook.
+    ///
+    /// # Arguments
+    /// * `event` - Event type: message, alert, house or summary.
+    /// * `message` - Plain text message.
+    /// * `house` - Fields of the house for house event.
+    pub(super) fn payload(
+        &self,
+        event: &std::primitive::str,
+        message: &std::primitive::str,
+        house: std::option::Option<&serde_json::Value>,
+    ) -> serde_json::Value {
+        match self {
+            WebhookKind::Json => serde_json::json!({
+                "event": event,
+                "message": message,
+                "house": house,
+            }),
+            WebhookKind::Discord => serde_json::json!({
+                // Discord does not accept longer content.
+                "content": message.chars().take(DISCORD_MAX_LENGTH).collect::<std::string::String>(),
+            }),
+            WebhookKind::Slack => serde_json::json!({
+                "text": message,
+            }),
+        }
+    }
+}
+
+/// Maximum length of Discord message content in characters.
+const DISCORD_MAX_LENGTH: std::primitive::usize = 2000;